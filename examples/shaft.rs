@@ -1,22 +1,23 @@
-use structural_shapes::{meters, StructuralShape};
+use structural_shapes::StructuralShape;
 use uom::fmt::DisplayStyle;
 use uom::si::f64::{Pressure, Torque};
-use uom::si::pressure::{megapascal, pascal};
+use uom::si::pressure::megapascal;
 use uom::si::torque::newton_meter;
 
 fn main() {
-    // Define height of cross-section
-    let r = meters(0.25);
-
     // Define the shape of the cross-section
-    let J = StructuralShape::new_pipe(0.5, 0.05).polar_moi();
+    let shaft = StructuralShape::new_pipe(0.5, 0.05);
+    let torsion_constant = shaft.torsion_constant();
+
+    // Outer radius of the shaft
+    let (_, (_, r)) = shaft.bounding_box();
 
     // Define the moment resisted by the cross-section
-    let T = Torque::new::<newton_meter>(10000.0);
+    let torque = Torque::new::<newton_meter>(10000.0);
 
     // Make a formatter to use with MPa
     let mpa = Pressure::format_args(megapascal, DisplayStyle::Abbreviation);
 
     // Compute and print stress
-    println!("{}", mpa.with(T * r / J));
+    println!("{}", mpa.with(torque * r / torsion_constant));
 }
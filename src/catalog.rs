@@ -0,0 +1,34 @@
+//! Catalogs of standard sections, looked up by their designations
+
+pub mod aisc;
+pub mod european;
+pub mod pipe;
+pub mod timber;
+
+/// Meters in an inch
+const INCH: f64 = 0.0254;
+
+/// Meters in a millimeter
+const MILLIMETER: f64 = 0.001;
+
+/// Put a designation in the form used in the catalogs, in capitals and without spaces
+fn normalize(designation: &str) -> String {
+    designation
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// The value of a size written in inches with a fraction, such as 1.5 for `1-1/2`
+fn inches(size: &str) -> Option<f64> {
+    size.split('-').try_fold(0.0, |total, part| {
+        let value = match part.split_once('/') {
+            Some((numerator, denominator)) => {
+                numerator.parse::<f64>().ok()? / denominator.parse::<f64>().ok()?
+            }
+            None => part.parse::<f64>().ok()?,
+        };
+        Some(total + value)
+    })
+}
@@ -0,0 +1,261 @@
+//! A selection of common American steel shapes from the AISC Shapes Database, with their
+//! published dimensions in inches.
+//!
+//! W and HP shapes are rolled I-beams whose root fillets make up the difference between the
+//! detailing distance `kdes` and the flange thickness. S and C shapes have flanges sloped at 2 in
+//! 12 and no fillets, which brings their weak-axis moments of inertia within about 2% of the
+//! tabulated ones. MC shapes, whose slopes vary, are taken to have parallel flanges of their
+//! average thickness, which can put their weak-axis moments of inertia several percent over. WT
+//! shapes are cut from the W shapes. Square and rectangular HSS have outside corner radii of twice
+//! their design wall thickness, and HSS and pipe have design wall thicknesses of 0.93 times their
+//! nominal ones, as in the AISC Manual.
+//! ```
+//! # use structural_shapes::catalog::aisc;
+//! let beam = aisc::lookup("W12X26").unwrap();
+//! let column = aisc::lookup("hss 8x8x1/2").unwrap();
+//! ```
+
+use super::{normalize, INCH};
+use crate::StructuralShape;
+
+/// Slope of the inside faces of the flanges of S and C shapes
+const FLANGE_SLOPE: f64 = 2.0 / 12.0;
+
+/// Ratio of the design wall thickness of HSS and pipe to their nominal wall thickness
+const DESIGN_THICKNESS: f64 = 0.93;
+
+/// W shapes, with their depth, flange width, web thickness, flange thickness and `kdes`
+const W: &[(&str, f64, f64, f64, f64, f64)] = &[
+    ("W6X9", 5.90, 3.94, 0.170, 0.215, 0.465),
+    ("W8X10", 7.89, 3.94, 0.170, 0.205, 0.505),
+    ("W8X31", 8.00, 8.00, 0.285, 0.435, 0.829),
+    ("W10X33", 9.73, 7.96, 0.290, 0.435, 0.935),
+    ("W10X49", 10.0, 10.0, 0.340, 0.560, 1.06),
+    ("W12X26", 12.2, 6.49, 0.230, 0.380, 0.680),
+    ("W12X50", 12.2, 8.08, 0.370, 0.640, 1.14),
+    ("W14X22", 13.7, 5.00, 0.230, 0.335, 0.735),
+    ("W14X90", 14.0, 14.5, 0.440, 0.710, 1.31),
+    ("W16X26", 15.7, 5.50, 0.250, 0.345, 0.747),
+    ("W18X35", 17.7, 6.00, 0.300, 0.425, 0.827),
+    ("W21X44", 20.7, 6.50, 0.350, 0.450, 0.950),
+    ("W24X55", 23.6, 7.01, 0.395, 0.505, 1.01),
+    ("W27X94", 26.9, 10.0, 0.490, 0.745, 1.34),
+    ("W30X99", 29.7, 10.5, 0.520, 0.670, 1.27),
+    ("W36X150", 35.9, 12.0, 0.625, 0.940, 1.69),
+];
+
+/// HP shapes, with their depth, flange width, web thickness, flange thickness and `kdes`
+const HP: &[(&str, f64, f64, f64, f64, f64)] = &[
+    ("HP8X36", 8.02, 8.16, 0.445, 0.445, 0.938),
+    ("HP10X42", 9.70, 10.1, 0.415, 0.420, 0.813),
+    ("HP12X53", 11.8, 12.0, 0.435, 0.435, 1.03),
+    ("HP14X73", 13.6, 14.6, 0.505, 0.505, 1.01),
+];
+
+/// S shapes, with their depth, flange width, web thickness and average flange thickness
+const S: &[(&str, f64, f64, f64, f64)] = &[
+    ("S6X12.5", 6.00, 3.33, 0.232, 0.359),
+    ("S8X18.4", 8.00, 4.00, 0.271, 0.426),
+    ("S10X35", 10.0, 4.94, 0.594, 0.491),
+    ("S12X31.8", 12.0, 5.00, 0.350, 0.544),
+    ("S24X80", 24.0, 7.00, 0.500, 0.870),
+];
+
+/// C shapes, with their depth, flange width, web thickness and average flange thickness
+const C: &[(&str, f64, f64, f64, f64)] = &[
+    ("C6X8.2", 6.00, 1.92, 0.200, 0.343),
+    ("C8X11.5", 8.00, 2.26, 0.220, 0.390),
+    ("C9X15", 9.00, 2.49, 0.285, 0.413),
+    ("C10X15.3", 10.0, 2.60, 0.240, 0.436),
+    ("C12X20.7", 12.0, 2.94, 0.282, 0.501),
+    ("C12X25", 12.0, 3.05, 0.387, 0.501),
+    ("C15X33.9", 15.0, 3.40, 0.400, 0.650),
+];
+
+/// MC shapes, with their depth, flange width, web thickness and average flange thickness
+const MC: &[(&str, f64, f64, f64, f64)] = &[
+    ("MC6X18", 6.00, 3.50, 0.379, 0.475),
+    ("MC8X20", 8.00, 3.03, 0.400, 0.500),
+    ("MC10X22", 10.0, 3.32, 0.290, 0.575),
+    ("MC12X31", 12.0, 3.67, 0.370, 0.700),
+];
+
+/// L shapes, with their long leg, short leg and thickness
+const L: &[(&str, f64, f64, f64)] = &[
+    ("L2X2X1/4", 2.0, 2.0, 0.25),
+    ("L3X3X1/4", 3.0, 3.0, 0.25),
+    ("L3X3X3/8", 3.0, 3.0, 0.375),
+    ("L4X4X1/4", 4.0, 4.0, 0.25),
+    ("L4X4X3/8", 4.0, 4.0, 0.375),
+    ("L4X4X1/2", 4.0, 4.0, 0.5),
+    ("L5X5X1/2", 5.0, 5.0, 0.5),
+    ("L6X6X1/2", 6.0, 6.0, 0.5),
+    ("L6X6X3/4", 6.0, 6.0, 0.75),
+    ("L8X8X1", 8.0, 8.0, 1.0),
+    ("L3-1/2X2-1/2X1/4", 3.5, 2.5, 0.25),
+    ("L4X3X3/8", 4.0, 3.0, 0.375),
+    ("L5X3X1/2", 5.0, 3.0, 0.5),
+    ("L6X4X1/2", 6.0, 4.0, 0.5),
+    ("L7X4X1/2", 7.0, 4.0, 0.5),
+    ("L8X6X1/2", 8.0, 6.0, 0.5),
+];
+
+/// Square and rectangular HSS, with their height, width and nominal wall thickness
+const RECTANGULAR_HSS: &[(&str, f64, f64, f64)] = &[
+    ("HSS4X2X1/4", 4.0, 2.0, 0.25),
+    ("HSS4X4X1/4", 4.0, 4.0, 0.25),
+    ("HSS6X4X1/4", 6.0, 4.0, 0.25),
+    ("HSS6X6X1/4", 6.0, 6.0, 0.25),
+    ("HSS6X6X3/8", 6.0, 6.0, 0.375),
+    ("HSS8X4X1/2", 8.0, 4.0, 0.5),
+    ("HSS8X6X3/8", 8.0, 6.0, 0.375),
+    ("HSS8X8X1/2", 8.0, 8.0, 0.5),
+    ("HSS10X6X3/8", 10.0, 6.0, 0.375),
+    ("HSS12X8X1/2", 12.0, 8.0, 0.5),
+];
+
+/// Round HSS and pipe, with their outside diameter and nominal wall thickness
+// A wall of 0.318 in is not an approximation of 1/π
+#[allow(clippy::approx_constant)]
+const ROUND: &[(&str, f64, f64)] = &[
+    ("HSS4.500X0.237", 4.500, 0.237),
+    ("HSS6.625X0.280", 6.625, 0.280),
+    ("HSS8.625X0.322", 8.625, 0.322),
+    ("HSS10.750X0.500", 10.750, 0.500),
+    ("Pipe1/2STD", 0.840, 0.109),
+    ("Pipe3/4STD", 1.050, 0.113),
+    ("Pipe1STD", 1.315, 0.133),
+    ("Pipe1-1/4STD", 1.660, 0.140),
+    ("Pipe1-1/2STD", 1.900, 0.145),
+    ("Pipe2STD", 2.375, 0.154),
+    ("Pipe2-1/2STD", 2.875, 0.203),
+    ("Pipe3STD", 3.500, 0.216),
+    ("Pipe3-1/2STD", 4.000, 0.226),
+    ("Pipe4STD", 4.500, 0.237),
+    ("Pipe5STD", 5.563, 0.258),
+    ("Pipe6STD", 6.625, 0.280),
+    ("Pipe8STD", 8.625, 0.322),
+    ("Pipe10STD", 10.750, 0.365),
+    ("Pipe12STD", 12.750, 0.375),
+    ("Pipe1/2XS", 0.840, 0.147),
+    ("Pipe3/4XS", 1.050, 0.154),
+    ("Pipe1XS", 1.315, 0.179),
+    ("Pipe1-1/4XS", 1.660, 0.191),
+    ("Pipe1-1/2XS", 1.900, 0.200),
+    ("Pipe2XS", 2.375, 0.218),
+    ("Pipe2-1/2XS", 2.875, 0.276),
+    ("Pipe3XS", 3.500, 0.300),
+    ("Pipe3-1/2XS", 4.000, 0.318),
+    ("Pipe4XS", 4.500, 0.337),
+    ("Pipe5XS", 5.563, 0.375),
+    ("Pipe6XS", 6.625, 0.432),
+    ("Pipe8XS", 8.625, 0.500),
+    ("Pipe10XS", 10.750, 0.500),
+    ("Pipe12XS", 12.750, 0.500),
+    ("Pipe2XXS", 2.375, 0.436),
+    ("Pipe2-1/2XXS", 2.875, 0.552),
+    ("Pipe3XXS", 3.500, 0.600),
+    ("Pipe4XXS", 4.500, 0.674),
+    ("Pipe5XXS", 5.563, 0.750),
+    ("Pipe6XXS", 6.625, 0.864),
+    ("Pipe8XXS", 8.625, 0.875),
+];
+
+/// Look up a shape by its AISC designation, such as `W12X26`, `WT6X13`, `L4X4X1/2`,
+/// `HSS8X4X1/2` or `Pipe4STD`, ignoring case and spaces. The shape is in meters, without COG.
+/// Shapes that are not in the catalog return `None`.
+/// ```
+/// # use structural_shapes::catalog::aisc;
+/// let shape = aisc::lookup("W12x26").unwrap();
+/// assert!(aisc::lookup("W12X27").is_none());
+/// ```
+pub fn lookup(designation: &str) -> Option<StructuralShape> {
+    let designation = normalize(designation);
+    if let Some(tee) = designation.strip_prefix("WT") {
+        // A WT is half of the W shape with twice its nominal depth and weight
+        let (depth, weight) = tee.split_once('X')?;
+        let (depth, weight) = (depth.parse::<f64>().ok()?, weight.parse::<f64>().ok()?);
+        return lookup(&format!("W{}X{}", 2.0 * depth, 2.0 * weight))?.cut_tee();
+    }
+    let found = |name: &str| normalize(name) == designation;
+    if let Some(&(_, d, bf, tw, tf, kdes)) = W.iter().chain(HP).find(|row| found(row.0)) {
+        return Some(StructuralShape::new_rolled_ibeam(
+            d * INCH,
+            bf * INCH,
+            tw * INCH,
+            tf * INCH,
+            (kdes - tf) * INCH,
+        ));
+    }
+    if let Some(&(_, d, bf, tw, tf)) = S.iter().find(|row| found(row.0)) {
+        return Some(StructuralShape::new_tapered_ibeam(
+            d * INCH,
+            bf * INCH,
+            tw * INCH,
+            tf * INCH,
+            FLANGE_SLOPE,
+        ));
+    }
+    if let Some(&(_, d, bf, tw, tf)) = C.iter().find(|row| found(row.0)) {
+        return Some(StructuralShape::new_tapered_channel(
+            d * INCH,
+            bf * INCH,
+            tw * INCH,
+            tf * INCH,
+            FLANGE_SLOPE,
+        ));
+    }
+    if let Some(&(_, d, bf, tw, tf)) = MC.iter().find(|row| found(row.0)) {
+        return Some(StructuralShape::new_channel(
+            d * INCH,
+            bf * INCH,
+            tw * INCH,
+            tf * INCH,
+        ));
+    }
+    if let Some(&(_, long, short, t)) = L.iter().find(|row| found(row.0)) {
+        return Some(StructuralShape::new_angle(
+            long * INCH,
+            short * INCH,
+            t * INCH,
+        ));
+    }
+    if let Some(&(_, h, b, t)) = RECTANGULAR_HSS.iter().find(|row| found(row.0)) {
+        let t = DESIGN_THICKNESS * t * INCH;
+        return Some(StructuralShape::new_rounded_boxbeam(
+            h * INCH,
+            b * INCH,
+            t,
+            2.0 * t,
+            t,
+        ));
+    }
+    ROUND
+        .iter()
+        .find(|row| found(row.0))
+        .map(|&(_, od, t)| StructuralShape::new_pipe(od * INCH / 2.0, DESIGN_THICKNESS * t * INCH))
+}
+
+/// All of the designations in the catalog, including the WT shapes cut from its W shapes
+/// ```
+/// # use structural_shapes::catalog::aisc;
+/// assert!(aisc::designations().contains(&"WT6X13".to_string()));
+/// ```
+pub fn designations() -> Vec<String> {
+    let names = W
+        .iter()
+        .map(|row| row.0)
+        .chain(HP.iter().map(|row| row.0))
+        .chain(S.iter().map(|row| row.0))
+        .chain(C.iter().map(|row| row.0))
+        .chain(MC.iter().map(|row| row.0))
+        .chain(L.iter().map(|row| row.0))
+        .chain(RECTANGULAR_HSS.iter().map(|row| row.0))
+        .chain(ROUND.iter().map(|row| row.0));
+    let tees = W.iter().map(|row| {
+        let (depth, weight) = row.0[1..].split_once('X').unwrap_or(("", ""));
+        let half = |value: &str| value.parse::<f64>().unwrap_or(0.0) / 2.0;
+        format!("WT{}X{}", half(depth), half(weight))
+    });
+    names.map(String::from).chain(tees).collect()
+}
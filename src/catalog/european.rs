@@ -0,0 +1,222 @@
+//! European rolled and hollow steel sections, with their dimensions in millimeters.
+//!
+//! IPE and HE (HEA, HEB and HEM) sections are rolled I-beams with root fillets. UPN channels have
+//! flanges sloped at 8% and no fillets, which puts their weak-axis moments of inertia about 5% over
+//! the tabulated ones, as the root fillets and rounded flange tips are left out. Hollow sections
+//! are read from their designations, such as `CHS 168.3x8`, `RHS 200x100x8` and `SHS 100x5`,
+//! with the corner radii of hot-finished sections to EN 10210-2: 1.5 times the wall thickness
+//! outside and once the wall thickness inside.
+//! ```
+//! # use structural_shapes::catalog::european;
+//! let beam = european::lookup("IPE 300").unwrap();
+//! let column = european::lookup("HE 200 B").unwrap();
+//! let brace = european::lookup("CHS 168.3x8").unwrap();
+//! ```
+
+use super::{normalize, MILLIMETER};
+use crate::StructuralShape;
+
+/// IPE sections, with their height, width, web thickness, flange thickness and root radius
+const IPE: &[(&str, f64, f64, f64, f64, f64)] = &[
+    ("IPE 80", 80.0, 46.0, 3.8, 5.2, 5.0),
+    ("IPE 100", 100.0, 55.0, 4.1, 5.7, 7.0),
+    ("IPE 120", 120.0, 64.0, 4.4, 6.3, 7.0),
+    ("IPE 140", 140.0, 73.0, 4.7, 6.9, 7.0),
+    ("IPE 160", 160.0, 82.0, 5.0, 7.4, 9.0),
+    ("IPE 180", 180.0, 91.0, 5.3, 8.0, 9.0),
+    ("IPE 200", 200.0, 100.0, 5.6, 8.5, 12.0),
+    ("IPE 220", 220.0, 110.0, 5.9, 9.2, 12.0),
+    ("IPE 240", 240.0, 120.0, 6.2, 9.8, 15.0),
+    ("IPE 270", 270.0, 135.0, 6.6, 10.2, 15.0),
+    ("IPE 300", 300.0, 150.0, 7.1, 10.7, 15.0),
+    ("IPE 330", 330.0, 160.0, 7.5, 11.5, 18.0),
+    ("IPE 360", 360.0, 170.0, 8.0, 12.7, 18.0),
+    ("IPE 400", 400.0, 180.0, 8.6, 13.5, 21.0),
+    ("IPE 450", 450.0, 190.0, 9.4, 14.6, 21.0),
+    ("IPE 500", 500.0, 200.0, 10.2, 16.0, 21.0),
+    ("IPE 550", 550.0, 210.0, 11.1, 17.2, 24.0),
+    ("IPE 600", 600.0, 220.0, 12.0, 19.0, 24.0),
+];
+
+/// HEA sections, with their height, width, web thickness, flange thickness and root radius
+const HEA: &[(&str, f64, f64, f64, f64, f64)] = &[
+    ("HEA 100", 96.0, 100.0, 5.0, 8.0, 12.0),
+    ("HEA 120", 114.0, 120.0, 5.0, 8.0, 12.0),
+    ("HEA 140", 133.0, 140.0, 5.5, 8.5, 12.0),
+    ("HEA 160", 152.0, 160.0, 6.0, 9.0, 15.0),
+    ("HEA 180", 171.0, 180.0, 6.0, 9.5, 15.0),
+    ("HEA 200", 190.0, 200.0, 6.5, 10.0, 18.0),
+    ("HEA 220", 210.0, 220.0, 7.0, 11.0, 18.0),
+    ("HEA 240", 230.0, 240.0, 7.5, 12.0, 21.0),
+    ("HEA 260", 250.0, 260.0, 7.5, 12.5, 24.0),
+    ("HEA 280", 270.0, 280.0, 8.0, 13.0, 24.0),
+    ("HEA 300", 290.0, 300.0, 8.5, 14.0, 27.0),
+    ("HEA 320", 310.0, 300.0, 9.0, 15.5, 27.0),
+    ("HEA 340", 330.0, 300.0, 9.5, 16.5, 27.0),
+    ("HEA 360", 350.0, 300.0, 10.0, 17.5, 27.0),
+    ("HEA 400", 390.0, 300.0, 11.0, 19.0, 27.0),
+    ("HEA 450", 440.0, 300.0, 11.5, 21.0, 27.0),
+    ("HEA 500", 490.0, 300.0, 12.0, 23.0, 27.0),
+    ("HEA 600", 590.0, 300.0, 13.0, 25.0, 27.0),
+];
+
+/// HEB sections, with their height, width, web thickness, flange thickness and root radius
+const HEB: &[(&str, f64, f64, f64, f64, f64)] = &[
+    ("HEB 100", 100.0, 100.0, 6.0, 10.0, 12.0),
+    ("HEB 120", 120.0, 120.0, 6.5, 11.0, 12.0),
+    ("HEB 140", 140.0, 140.0, 7.0, 12.0, 12.0),
+    ("HEB 160", 160.0, 160.0, 8.0, 13.0, 15.0),
+    ("HEB 180", 180.0, 180.0, 8.5, 14.0, 15.0),
+    ("HEB 200", 200.0, 200.0, 9.0, 15.0, 18.0),
+    ("HEB 220", 220.0, 220.0, 9.5, 16.0, 18.0),
+    ("HEB 240", 240.0, 240.0, 10.0, 17.0, 21.0),
+    ("HEB 260", 260.0, 260.0, 10.0, 17.5, 24.0),
+    ("HEB 280", 280.0, 280.0, 10.5, 18.0, 24.0),
+    ("HEB 300", 300.0, 300.0, 11.0, 19.0, 27.0),
+    ("HEB 320", 320.0, 300.0, 11.5, 20.5, 27.0),
+    ("HEB 340", 340.0, 300.0, 12.0, 21.5, 27.0),
+    ("HEB 360", 360.0, 300.0, 12.5, 22.5, 27.0),
+    ("HEB 400", 400.0, 300.0, 13.5, 24.0, 27.0),
+    ("HEB 450", 450.0, 300.0, 14.0, 26.0, 27.0),
+    ("HEB 500", 500.0, 300.0, 14.5, 28.0, 27.0),
+    ("HEB 600", 600.0, 300.0, 15.5, 30.0, 27.0),
+];
+
+/// HEM sections, with their height, width, web thickness, flange thickness and root radius
+const HEM: &[(&str, f64, f64, f64, f64, f64)] = &[
+    ("HEM 100", 120.0, 106.0, 12.0, 20.0, 12.0),
+    ("HEM 120", 140.0, 126.0, 12.5, 21.0, 12.0),
+    ("HEM 140", 160.0, 146.0, 13.0, 22.0, 12.0),
+    ("HEM 160", 180.0, 166.0, 14.0, 23.0, 15.0),
+    ("HEM 180", 200.0, 186.0, 14.5, 24.0, 15.0),
+    ("HEM 200", 220.0, 206.0, 15.0, 25.0, 18.0),
+    ("HEM 220", 240.0, 226.0, 15.5, 26.0, 18.0),
+    ("HEM 240", 270.0, 248.0, 18.0, 32.0, 21.0),
+    ("HEM 260", 290.0, 268.0, 18.0, 32.5, 24.0),
+    ("HEM 280", 310.0, 288.0, 18.5, 33.0, 24.0),
+    ("HEM 300", 340.0, 310.0, 21.0, 39.0, 27.0),
+    ("HEM 320", 359.0, 309.0, 21.0, 40.0, 27.0),
+    ("HEM 340", 377.0, 309.0, 21.0, 40.0, 27.0),
+    ("HEM 360", 395.0, 308.0, 21.0, 40.0, 27.0),
+    ("HEM 400", 432.0, 307.0, 21.0, 40.0, 27.0),
+];
+
+/// Slope of the inside faces of the flanges of UPN channels
+const FLANGE_SLOPE: f64 = 0.08;
+
+/// UPN channels, with their height, width, web thickness and flange thickness
+const UPN: &[(&str, f64, f64, f64, f64)] = &[
+    ("UPN 80", 80.0, 45.0, 6.0, 8.0),
+    ("UPN 100", 100.0, 50.0, 6.0, 8.5),
+    ("UPN 120", 120.0, 55.0, 7.0, 9.0),
+    ("UPN 140", 140.0, 60.0, 7.0, 10.0),
+    ("UPN 160", 160.0, 65.0, 7.5, 10.5),
+    ("UPN 180", 180.0, 70.0, 8.0, 11.0),
+    ("UPN 200", 200.0, 75.0, 8.5, 11.5),
+    ("UPN 220", 220.0, 80.0, 9.0, 12.5),
+    ("UPN 240", 240.0, 85.0, 9.5, 13.0),
+    ("UPN 260", 260.0, 90.0, 10.0, 14.0),
+    ("UPN 280", 280.0, 95.0, 10.0, 15.0),
+    ("UPN 300", 300.0, 100.0, 10.0, 16.0),
+];
+
+/// Look up a shape by its European designation, such as `IPE 300`, `HEB 200` (or `HE 200 B`),
+/// `UPN 160`, `CHS 168.3x8`, `RHS 200x100x8` or `SHS 100x5`, ignoring case and spaces. The shape
+/// is in meters, without COG. Rolled sections that are not in the catalog, and hollow sections
+/// whose dimensions cannot be read or whose walls are too thick, return `None`.
+/// ```
+/// # use structural_shapes::catalog::european;
+/// let shape = european::lookup("RHS 200x100x8").unwrap();
+/// assert!(european::lookup("IPE 310").is_none());
+/// ```
+pub fn lookup(designation: &str) -> Option<StructuralShape> {
+    let mut designation = normalize(designation);
+    // Also accept the series written after the size, as in HE 200 B
+    if let Some(rest) = designation.strip_prefix("HE") {
+        if let Some(series) = rest.chars().last().filter(|c| "ABM".contains(*c)) {
+            if rest[..rest.len() - 1].chars().all(|c| c.is_ascii_digit()) {
+                designation = format!("HE{}{}", series, &rest[..rest.len() - 1]);
+            }
+        }
+    }
+    if let Some(sizes) = designation.strip_prefix("CHS") {
+        return match dimensions(sizes)?[..] {
+            [diameter, thickness] if 2.0 * thickness < diameter => {
+                Some(StructuralShape::new_pipe(diameter / 2.0, thickness))
+            }
+            _ => None,
+        };
+    }
+    let hollow = match designation.get(..3) {
+        Some("RHS") => dimensions(&designation[3..]),
+        Some("SHS") => dimensions(&designation[3..]).map(|sizes| match sizes[..] {
+            [width, thickness] => vec![width, width, thickness],
+            _ => sizes,
+        }),
+        _ => None,
+    };
+    if let Some(sizes) = hollow {
+        return match sizes[..] {
+            [height, width, thickness] if 2.0 * thickness < height.min(width) => {
+                Some(StructuralShape::new_rounded_boxbeam(
+                    height,
+                    width,
+                    thickness,
+                    1.5 * thickness,
+                    thickness,
+                ))
+            }
+            _ => None,
+        };
+    }
+    let found = |name: &str| normalize(name) == designation;
+    let mut rolled = IPE.iter().chain(HEA).chain(HEB).chain(HEM);
+    if let Some(&(_, h, b, tw, tf, r)) = rolled.find(|row| found(row.0)) {
+        return Some(StructuralShape::new_rolled_ibeam(
+            h * MILLIMETER,
+            b * MILLIMETER,
+            tw * MILLIMETER,
+            tf * MILLIMETER,
+            r * MILLIMETER,
+        ));
+    }
+    UPN.iter()
+        .find(|row| found(row.0))
+        .map(|&(_, h, b, tw, tf)| {
+            StructuralShape::new_tapered_channel(
+                h * MILLIMETER,
+                b * MILLIMETER,
+                tw * MILLIMETER,
+                tf * MILLIMETER,
+                FLANGE_SLOPE,
+            )
+        })
+}
+
+/// All of the designations of rolled sections in the catalog. Hollow sections are read from
+/// their designations, so they are not listed.
+/// ```
+/// # use structural_shapes::catalog::european;
+/// assert!(european::designations().contains(&"HEB 200"));
+/// ```
+pub fn designations() -> Vec<&'static str> {
+    IPE.iter()
+        .chain(HEA)
+        .chain(HEB)
+        .chain(HEM)
+        .map(|row| row.0)
+        .chain(UPN.iter().map(|row| row.0))
+        .collect()
+}
+
+/// Dimensions separated by `X` in a designation, converted from millimeters to meters
+fn dimensions(text: &str) -> Option<Vec<f64>> {
+    text.split('X')
+        .map(|size| {
+            size.parse::<f64>()
+                .ok()
+                .filter(|value| *value > 0.0)
+                .map(|value| value * MILLIMETER)
+        })
+        .collect()
+}
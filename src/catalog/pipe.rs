@@ -0,0 +1,102 @@
+//! Welded and seamless steel pipe to ASME B36.10M, by nominal pipe size (NPS) or diameter
+//! nominal (DN) and schedule. Pipes have their nominal wall thickness, without the mill
+//! tolerance.
+//! ```
+//! # use structural_shapes::catalog::pipe::{lookup, Schedule};
+//! let line = lookup("4", Schedule::S40).unwrap();
+//! let drain = lookup("DN 50", Schedule::ExtraStrong).unwrap();
+//! let small = lookup("1-1/2", Schedule::S80).unwrap();
+//! ```
+
+use super::{inches, INCH};
+use crate::StructuralShape;
+
+/// A pipe schedule, which sets the wall thickness for each nominal size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /// Schedule 10
+    S10,
+    /// Schedule 40
+    S40,
+    /// Schedule 80
+    S80,
+    /// Schedule 160
+    S160,
+    /// Standard weight (STD)
+    Standard,
+    /// Extra strong (XS)
+    ExtraStrong,
+    /// Double extra strong (XXS)
+    DoubleExtraStrong,
+}
+
+/// Nominal pipe sizes, with their diameter nominal, outside diameter and wall thickness for each
+/// schedule in the order of `Schedule`, all in inches, or zero where a size has no such schedule
+// A wall of 0.318 in is not an approximation of 1/π
+#[allow(clippy::approx_constant)]
+#[rustfmt::skip]
+const SIZES: &[(&str, u32, f64, [f64; 7])] = &[
+    ("1/8", 6, 0.405, [0.049, 0.068, 0.095, 0.0, 0.068, 0.095, 0.0]),
+    ("1/4", 8, 0.540, [0.065, 0.088, 0.119, 0.0, 0.088, 0.119, 0.0]),
+    ("3/8", 10, 0.675, [0.065, 0.091, 0.126, 0.0, 0.091, 0.126, 0.0]),
+    ("1/2", 15, 0.840, [0.083, 0.109, 0.147, 0.188, 0.109, 0.147, 0.294]),
+    ("3/4", 20, 1.050, [0.083, 0.113, 0.154, 0.219, 0.113, 0.154, 0.308]),
+    ("1", 25, 1.315, [0.109, 0.133, 0.179, 0.250, 0.133, 0.179, 0.358]),
+    ("1-1/4", 32, 1.660, [0.109, 0.140, 0.191, 0.250, 0.140, 0.191, 0.382]),
+    ("1-1/2", 40, 1.900, [0.109, 0.145, 0.200, 0.281, 0.145, 0.200, 0.400]),
+    ("2", 50, 2.375, [0.109, 0.154, 0.218, 0.344, 0.154, 0.218, 0.436]),
+    ("2-1/2", 65, 2.875, [0.120, 0.203, 0.276, 0.375, 0.203, 0.276, 0.552]),
+    ("3", 80, 3.500, [0.120, 0.216, 0.300, 0.438, 0.216, 0.300, 0.600]),
+    ("3-1/2", 90, 4.000, [0.120, 0.226, 0.318, 0.0, 0.226, 0.318, 0.0]),
+    ("4", 100, 4.500, [0.120, 0.237, 0.337, 0.531, 0.237, 0.337, 0.674]),
+    ("5", 125, 5.563, [0.134, 0.258, 0.375, 0.625, 0.258, 0.375, 0.750]),
+    ("6", 150, 6.625, [0.134, 0.280, 0.432, 0.719, 0.280, 0.432, 0.864]),
+    ("8", 200, 8.625, [0.148, 0.322, 0.500, 0.906, 0.322, 0.500, 0.875]),
+    ("10", 250, 10.750, [0.165, 0.365, 0.594, 1.125, 0.365, 0.500, 1.000]),
+    ("12", 300, 12.750, [0.180, 0.406, 0.688, 1.312, 0.375, 0.500, 1.000]),
+    ("14", 350, 14.000, [0.250, 0.438, 0.750, 1.406, 0.375, 0.500, 0.0]),
+    ("16", 400, 16.000, [0.250, 0.500, 0.844, 1.594, 0.375, 0.500, 0.0]),
+    ("18", 450, 18.000, [0.250, 0.562, 0.938, 1.781, 0.375, 0.500, 0.0]),
+    ("20", 500, 20.000, [0.250, 0.594, 1.031, 1.969, 0.375, 0.500, 0.0]),
+    ("24", 600, 24.000, [0.250, 0.688, 1.219, 2.344, 0.375, 0.500, 0.0]),
+];
+
+/// Make a pipe from its nominal size and schedule, in meters without COG. The size is an NPS such
+/// as `4`, `1-1/2`, `1 1/2` or `1.5`, or a DN such as `DN100`. Sizes that are not in the table,
+/// and schedules that are not made in a size, return `None`.
+/// ```
+/// # use structural_shapes::catalog::pipe::{lookup, Schedule};
+/// let shape = lookup("4", Schedule::S40).unwrap();
+/// assert!(lookup("3-1/2", Schedule::S160).is_none());
+/// ```
+pub fn lookup(size: &str, schedule: Schedule) -> Option<StructuralShape> {
+    let size = size.split_whitespace().collect::<Vec<_>>().join("-");
+    let size = size.to_uppercase();
+    let row = match size.strip_prefix("DN") {
+        Some(dn) => {
+            let dn = dn.trim_start_matches('-').parse::<u32>().ok()?;
+            SIZES.iter().find(|row| row.1 == dn)
+        }
+        None => SIZES
+            .iter()
+            .find(|row| row.0 == size || inches(row.0) == size.parse::<f64>().ok()),
+    }?;
+    let (outside_diameter, thickness) = (row.2, row.3[schedule as usize]);
+    if thickness > 0.0 {
+        Some(StructuralShape::new_pipe(
+            outside_diameter * INCH / 2.0,
+            thickness * INCH,
+        ))
+    } else {
+        None
+    }
+}
+
+/// The nominal sizes in the table, from smallest to largest
+/// ```
+/// # use structural_shapes::catalog::pipe;
+/// assert_eq!(pipe::sizes()[0], "1/8");
+/// ```
+pub fn sizes() -> Vec<&'static str> {
+    SIZES.iter().map(|row| row.0).collect()
+}
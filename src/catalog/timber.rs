@@ -0,0 +1,154 @@
+//! Sawn lumber and engineered wood beams, by their sizes in inches.
+//!
+//! Sawn lumber is called by its nominal size, which is larger than the dressed piece: a `2x4` is
+//! 1-1/2 in by 3-1/2 in. Boards and dimension lumber are dressed dry to American Softwood Lumber
+//! Standard PS 20, and timbers 5 in and thicker are dressed 1/2 in under nominal on both faces.
+//! Glulam and LVL are called by their actual sizes, so they are only checked against the
+//! standard widths and depths. Every shape is a rectangle standing on edge, with its depth along
+//! the y axis, in meters and without COG.
+//! ```
+//! # use structural_shapes::catalog::timber;
+//! let joist = timber::lumber("2x10").unwrap();
+//! let post = timber::lumber("6x6").unwrap();
+//! let header = timber::glulam("5-1/8 x 12").unwrap();
+//! let beam = timber::lvl("1-3/4 x 11-7/8").unwrap();
+//! ```
+
+use super::{inches, INCH};
+use crate::StructuralShape;
+
+/// Nominal and dressed sizes of boards and dimension lumber, less than 5 in thick
+const LUMBER: &[(f64, f64)] = &[
+    (1.0, 0.75),
+    (2.0, 1.5),
+    (3.0, 2.5),
+    (4.0, 3.5),
+    (5.0, 4.5),
+    (6.0, 5.5),
+    (8.0, 7.25),
+    (10.0, 9.25),
+    (12.0, 11.25),
+    (14.0, 13.25),
+    (16.0, 15.25),
+];
+
+/// Nominal and dressed sizes of timbers, 5 in thick and up
+const TIMBERS: &[(f64, f64)] = &[
+    (5.0, 4.5),
+    (6.0, 5.5),
+    (8.0, 7.5),
+    (10.0, 9.5),
+    (12.0, 11.5),
+    (14.0, 13.5),
+    (16.0, 15.5),
+];
+
+/// Standard glulam widths, with the thickness of the laminations they are made from: 1-1/2 in for
+/// western species and 1-3/8 in for southern pine
+const GLULAM: &[(f64, f64)] = &[
+    (3.125, 1.5),
+    (5.125, 1.5),
+    (6.75, 1.5),
+    (8.75, 1.5),
+    (10.75, 1.5),
+    (12.25, 1.5),
+    (3.0, 1.375),
+    (5.0, 1.375),
+    (6.75, 1.375),
+    (8.5, 1.375),
+    (10.5, 1.375),
+];
+
+/// The fewest laminations in a glulam beam
+const MINIMUM_LAMINATIONS: f64 = 4.0;
+
+/// Standard LVL widths, as a single 1-3/4 in ply or several of them fastened together
+const LVL_WIDTHS: &[f64] = &[1.75, 3.5, 5.25, 7.0];
+
+/// Standard LVL depths
+const LVL_DEPTHS: &[f64] = &[
+    5.5, 7.25, 9.25, 9.5, 11.25, 11.875, 14.0, 16.0, 18.0, 20.0, 24.0,
+];
+
+/// Make a piece of sawn lumber from its nominal size, such as `2x4`, `2 X 10` or `6x6`, with the
+/// larger dimension as the depth. Sizes that are not standard return `None`.
+/// ```
+/// # use structural_shapes::catalog::timber;
+/// # use structural_shapes::StructuralShape;
+/// let stud = timber::lumber("2x4").unwrap();
+/// let dressed = StructuralShape::new_rectangle(3.5 * 0.0254, 1.5 * 0.0254);
+/// assert_eq!(stud.area(), dressed.area());
+/// assert!(timber::lumber("2x7").is_none());
+/// ```
+pub fn lumber(size: &str) -> Option<StructuralShape> {
+    let (width, depth) = dimensions(size)?;
+    let table = if width >= 5.0 { TIMBERS } else { LUMBER };
+    let dressed = |nominal: f64| {
+        table
+            .iter()
+            .find(|row| row.0 == nominal)
+            .map(|row| row.1 * INCH)
+    };
+    Some(StructuralShape::new_rectangle(
+        dressed(depth)?,
+        dressed(width)?,
+    ))
+}
+
+/// Make a glulam beam from its actual size, such as `5-1/8x12` or `6-3/4 x 24-3/4`. The width must
+/// be a standard width and the depth a whole number of its laminations, at least four of them, or
+/// `None` is returned.
+/// ```
+/// # use structural_shapes::catalog::timber;
+/// let western = timber::glulam("3-1/8x9").unwrap();
+/// let southern = timber::glulam("5x11").unwrap();
+/// assert!(timber::glulam("5x12").is_none());
+/// ```
+pub fn glulam(size: &str) -> Option<StructuralShape> {
+    let (width, depth) = dimensions(size)?;
+    GLULAM
+        .iter()
+        .filter(|row| close(row.0, width))
+        .map(|row| depth / row.1)
+        .find(|laminations| close(*laminations, laminations.round()))
+        .filter(|laminations| laminations.round() >= MINIMUM_LAMINATIONS)
+        .map(|_| StructuralShape::new_rectangle(depth * INCH, width * INCH))
+}
+
+/// Make an LVL beam from its actual size, such as `1-3/4x11-7/8` or `3.5 x 14`. Sizes that are
+/// not standard return `None`.
+/// ```
+/// # use structural_shapes::catalog::timber;
+/// let beam = timber::lvl("1-3/4x9-1/4").unwrap();
+/// assert!(timber::lvl("2x10").is_none());
+/// ```
+pub fn lvl(size: &str) -> Option<StructuralShape> {
+    let (width, depth) = dimensions(size)?;
+    if LVL_WIDTHS.iter().any(|w| close(*w, width)) && LVL_DEPTHS.iter().any(|d| close(*d, depth)) {
+        Some(StructuralShape::new_rectangle(depth * INCH, width * INCH))
+    } else {
+        None
+    }
+}
+
+/// The width and depth in a size written as two dimensions in inches separated by `x`, with the
+/// smaller dimension taken as the width
+fn dimensions(size: &str) -> Option<(f64, f64)> {
+    let size = size.to_uppercase();
+    let parts = size
+        .split('X')
+        .map(|part| {
+            let part = part.split_whitespace().collect::<Vec<_>>().join("-");
+            inches(&part).filter(|value| *value > 0.0)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    match parts[..] {
+        [a, b] => Some((a.min(b), a.max(b))),
+        _ => None,
+    }
+}
+
+/// Whether two sizes in inches are the same, allowing for rounding in fractions
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6
+}
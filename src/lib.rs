@@ -3,21 +3,58 @@
 #![warn(clippy::missing_docs_in_private_items)]
 #![doc = include_str!("../README.md")]
 
+pub mod catalog;
+mod geometry;
+mod thin_walled;
+
 use num::{Float, NumCast};
-use typenum::{P4, Z0};
+use typenum::{P4, P6, Z0};
 use uom::si::{
-    f64::{Area, Length, Volume},
+    angle::{degree, radian},
+    area::square_meter,
+    f64::{Angle, Area, Length, Volume},
     length::meter,
+    volume::cubic_meter,
     {Quantity, ISQ, SI},
 };
+/// Second moment of area, with dimensions of length to the fourth power
 type SecondAreaMomentofInertia = Quantity<ISQ<P4, Z0, Z0, Z0, Z0, Z0, Z0>, SI<f64>, f64>;
+/// Warping constant, with dimensions of length to the sixth power
+type WarpingConstant = Quantity<ISQ<P6, Z0, Z0, Z0, Z0, Z0, Z0>, SI<f64>, f64>;
+/// Number of straight pieces used to trace each bend of a cold-formed shape
+const BEND_SEGMENTS: usize = 16;
 
 /// A helper function supporting conversion of floating point numbers to meters
 pub fn meters<T: Float>(l: T) -> Length {
     Length::new::<meter>(NumCast::from(l).expect("The input must be castable to a float."))
 }
 
+/// A helper function supporting conversion of floating point numbers to radians
+pub fn radians<T: Float>(a: T) -> Angle {
+    Angle::new::<radian>(NumCast::from(a).expect("The input must be castable to a float."))
+}
+
+/// A helper function supporting conversion of floating point numbers to degrees
+pub fn degrees<T: Float>(a: T) -> Angle {
+    Angle::new::<degree>(NumCast::from(a).expect("The input must be castable to a float."))
+}
+
 /// This enum contains different structural shapes
+///
+/// # Axis convention
+/// Shapes lie in the x-y plane of the cross-section, with x horizontal and y vertical. Widths are
+/// measured along x and heights along y, so `moi_x` is the strong-axis moment of an I-beam. The
+/// `center_of_gravity` of a shape is the location of its centroid, and `rotation` turns the shape
+/// counterclockwise about that point, so the widths and heights above describe the unrotated shape.
+///
+/// `moi_x`, `moi_y` and `product_moi` are the integrals of y², x² and xy over the area, taken
+/// about the reference axes through the origin. They include the parallel-axis terms from
+/// `center_of_gravity`, which lets composites sum their members directly. The `centroidal_*`
+/// functions give the same quantities about axes through the center of gravity, and `moi_about`
+/// gives the moment about any other axis.
+///
+/// This differs from version 0.2, which paired `center_of_gravity.0` with the x-axis moment, so
+/// `moi_x` and `moi_y` of a shape away from the origin give different values than they did there.
 #[derive(Clone, Copy, Debug)]
 #[non_exhaustive]
 pub enum StructuralShape {
@@ -29,6 +66,8 @@ pub enum StructuralShape {
         thickness: Length,
         /// Coordinates of center of gravity
         center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
     },
     /// This is an I-Beam, with a width, height, web thickness, and flange thickness
     IBeam {
@@ -38,21 +77,39 @@ pub enum StructuralShape {
         height: Length,
         /// Thickness of the web
         web_thickness: Length,
-        /// Thickness of the flange
+        /// Thickness of the flange, halfway along the inside face of a sloped flange
         flange_thickness: Length,
+        /// Radius of the root fillets between the web and the flanges
+        root_radius: Length,
+        /// Slope of the inside faces of the flanges, as rise over run, which is zero for parallel
+        /// flanges
+        flange_slope: f64,
         /// Coordinates of center of gravity
         center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
     },
-    /// This is a box beam with a width, height, and thickness
+    /// This is a box beam with a width, height, flange and web thicknesses, and webs that may be
+    /// set in from the edges of the flanges
     BoxBeam {
-        /// Width of the box beam
+        /// Width of the box beam, across the flanges
         width: Length,
         /// Height of the box beam
         height: Length,
-        /// Thickness of the wall
-        thickness: Length,
+        /// Thickness of the top and bottom flanges
+        flange_thickness: Length,
+        /// Thickness of the two webs
+        web_thickness: Length,
+        /// Distance from each edge of the flanges to the outside face of the web below it
+        overhang: Length,
+        /// Radius of the outside corners, at the tips of the flanges
+        outer_radius: Length,
+        /// Radius of the inside corners
+        inner_radius: Length,
         /// Coordinates of center of gravity
         center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
     },
     /// This is a rod with a radius only
     Rod {
@@ -60,6 +117,8 @@ pub enum StructuralShape {
         radius: Length,
         /// Coordinates of center of gravity
         center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
     },
     /// This is a solid rectangular with width and height
     Rectangle {
@@ -69,6 +128,301 @@ pub enum StructuralShape {
         height: Length,
         /// Coordinates of center of gravity
         center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a channel with a height, flange width, web thickness, and flange thickness. The web
+    /// is on the left and the flanges point to the right.
+    Channel {
+        /// Width of the flanges, including the web
+        width: Length,
+        /// Height of the channel
+        height: Length,
+        /// Thickness of the web
+        web_thickness: Length,
+        /// Thickness of the flanges, halfway along their inside faces if they are sloped
+        flange_thickness: Length,
+        /// Slope of the inside faces of the flanges, as rise over run, which is zero for parallel
+        /// flanges
+        flange_slope: f64,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a tee with a flange across the top and a stem hanging from its center, such as a WT
+    /// cut from an I-beam
+    Tee {
+        /// Width of the flange
+        width: Length,
+        /// Height of the tee, including the flange
+        height: Length,
+        /// Thickness of the stem
+        web_thickness: Length,
+        /// Thickness of the flange
+        flange_thickness: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a precast double tee with a flange across the top and two stems of constant width,
+    /// placed symmetrically about the center
+    DoubleTee {
+        /// Width of the flange
+        width: Length,
+        /// Height of the double tee, including the flange
+        height: Length,
+        /// Thickness of the flange
+        flange_thickness: Length,
+        /// Width of each stem
+        stem_width: Length,
+        /// Distance between the centerlines of the stems
+        stem_spacing: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is an angle with a vertical leg, a horizontal leg, and a thickness. The heel is at the
+    /// lower left, with one leg pointing up and the other to the right. The legs may be unequal.
+    Angle {
+        /// Length of the horizontal leg
+        width: Length,
+        /// Length of the vertical leg
+        height: Length,
+        /// Thickness of the legs
+        thickness: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a cold-formed lipped channel, with the web on the left and the flanges to the right.
+    /// The lips turn in towards each other. Dimensions are measured along the centerline of the
+    /// wall, between the intersections of the straight parts.
+    LippedChannel {
+        /// Width of the flanges
+        width: Length,
+        /// Height of the web
+        height: Length,
+        /// Length of the lips, which may be zero
+        lip: Length,
+        /// Thickness of the wall
+        thickness: Length,
+        /// Inner radius of the bends
+        bend_radius: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a cold-formed lipped Z-section, with the top flange to the right of the web and the
+    /// bottom flange to the left. Dimensions are measured along the centerline of the wall, between
+    /// the intersections of the straight parts.
+    LippedZ {
+        /// Width of the flanges
+        width: Length,
+        /// Height of the web
+        height: Length,
+        /// Length of the lips, which may be zero
+        lip: Length,
+        /// Thickness of the wall
+        thickness: Length,
+        /// Inner radius of the bends
+        bend_radius: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a cold-formed sigma section, a lipped channel whose web is pushed in towards the
+    /// flanges at mid-height. Dimensions are measured along the centerline of the wall, between the
+    /// intersections of the straight parts.
+    Sigma {
+        /// Width of the flanges
+        width: Length,
+        /// Height of the web
+        height: Length,
+        /// Length of the lips, which may be zero
+        lip: Length,
+        /// Length of the straight part of the web next to each flange
+        web_flat: Length,
+        /// Distance the middle of the web is pushed in
+        indent: Length,
+        /// Height of the pushed-in middle of the web
+        indent_height: Length,
+        /// Thickness of the wall
+        thickness: Length,
+        /// Inner radius of the bends
+        bend_radius: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a cold-formed hat section, with the crown on top and the flanges turned out at the
+    /// bottom of the webs. Dimensions are measured along the centerline of the wall, between the
+    /// intersections of the straight parts.
+    Hat {
+        /// Width of the crown
+        width: Length,
+        /// Height of the webs
+        height: Length,
+        /// Width of each flange
+        flange: Length,
+        /// Thickness of the wall
+        thickness: Length,
+        /// Inner radius of the bends
+        bend_radius: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a solid ellipse with a width and height
+    Ellipse {
+        /// Width of the ellipse, along its x-axis
+        width: Length,
+        /// Height of the ellipse, along its y-axis
+        height: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is an elliptical hollow section with an outer width and height and a wall thickness.
+    /// As in the product standards, the inside is taken to be an ellipse with semi-axes one
+    /// thickness smaller than those outside.
+    EllipticalHollow {
+        /// Outer width of the section, along its x-axis
+        width: Length,
+        /// Outer height of the section, along its y-axis
+        height: Length,
+        /// Thickness of the wall
+        thickness: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a triangle with a horizontal base and an apex above it
+    Triangle {
+        /// Width of the base
+        width: Length,
+        /// Height of the apex above the base
+        height: Length,
+        /// Horizontal distance from the left end of the base to the apex
+        apex: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a trapezoid with horizontal top and bottom edges
+    Trapezoid {
+        /// Width of the bottom edge
+        bottom_width: Length,
+        /// Width of the top edge
+        top_width: Length,
+        /// Height of the trapezoid
+        height: Length,
+        /// Horizontal distance from the left end of the bottom edge to the left end of the top edge
+        top_offset: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a semicircle with its straight edge at the bottom
+    Semicircle {
+        /// Radius of the semicircle
+        radius: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a circular segment, the part of a circle cut off by a chord, with the chord
+    /// horizontal at the bottom
+    CircularSegment {
+        /// Radius of the circle
+        radius: Length,
+        /// Angle subtended by the arc at the center of the circle
+        angle: Angle,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a circular sector with its apex at the bottom, symmetric about a vertical line
+    CircularSector {
+        /// Radius of the sector
+        radius: Length,
+        /// Angle between the two straight edges
+        angle: Angle,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is an annular sector, such as a curved plate, with the center of its arcs below it and
+    /// symmetric about a vertical line
+    AnnularSector {
+        /// Radius of the outer arc
+        outer_radius: Length,
+        /// Thickness between the arcs
+        thickness: Length,
+        /// Angle subtended by the arcs at their center
+        angle: Angle,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a plate girder, an I-beam whose top and bottom flanges may differ
+    PlateGirder {
+        /// Height of the girder
+        height: Length,
+        /// Thickness of the web
+        web_thickness: Length,
+        /// Width of the top flange
+        top_width: Length,
+        /// Thickness of the top flange
+        top_thickness: Length,
+        /// Width of the bottom flange
+        bottom_width: Length,
+        /// Thickness of the bottom flange
+        bottom_thickness: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a regular polygon with a number of sides and a circumradius, standing on a flat
+    RegularPolygon {
+        /// Number of sides
+        sides: usize,
+        /// Distance from the center to each corner
+        radius: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
+    },
+    /// This is a hollow regular polygon with a number of sides, a circumradius and a wall
+    /// thickness, standing on a flat, such as a polygonal tube for a lighting pole
+    HollowRegularPolygon {
+        /// Number of sides
+        sides: usize,
+        /// Distance from the center to each outside corner
+        radius: Length,
+        /// Thickness of the wall, measured square to the flats
+        thickness: Length,
+        /// Coordinates of center of gravity
+        center_of_gravity: (Length, Length),
+        /// Counterclockwise rotation about the center of gravity
+        rotation: Angle,
     },
 }
 
@@ -82,6 +436,7 @@ impl StructuralShape {
         StructuralShape::Rod {
             radius: meters(radius),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         }
     }
 
@@ -95,6 +450,7 @@ impl StructuralShape {
             outer_radius: meters(radius),
             thickness: meters(thickness),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         }
     }
 
@@ -108,6 +464,7 @@ impl StructuralShape {
             width: meters(width),
             height: meters(height),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         }
     }
 
@@ -117,11 +474,57 @@ impl StructuralShape {
     /// let shape = StructuralShape::new_boxbeam(2.0, 2.0, 0.15);
     /// ```
     pub fn new_boxbeam(height: f64, width: f64, thickness: f64) -> StructuralShape {
+        StructuralShape::new_rounded_boxbeam(height, width, thickness, 0.0, 0.0)
+    }
+
+    /// Make a new boxbeam with rounded corners without COG, such as a rectangular hollow section
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rounded_boxbeam(0.2, 0.1, 0.008, 0.016, 0.008);
+    /// ```
+    pub fn new_rounded_boxbeam(
+        height: f64,
+        width: f64,
+        thickness: f64,
+        outer_radius: f64,
+        inner_radius: f64,
+    ) -> StructuralShape {
         StructuralShape::BoxBeam {
             width: meters(width),
             height: meters(height),
-            thickness: meters(thickness),
+            flange_thickness: meters(thickness),
+            web_thickness: meters(thickness),
+            overhang: meters(0.0),
+            outer_radius: meters(outer_radius),
+            inner_radius: meters(inner_radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new box girder without COG, with its own flange and web thicknesses and with the
+    /// webs set in from the edges of the flanges by an overhang, which may be zero
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_box_girder(1.5, 1.2, 0.03, 0.015, 0.1);
+    /// ```
+    pub fn new_box_girder(
+        height: f64,
+        width: f64,
+        flange_thickness: f64,
+        web_thickness: f64,
+        overhang: f64,
+    ) -> StructuralShape {
+        StructuralShape::BoxBeam {
+            width: meters(width),
+            height: meters(height),
+            flange_thickness: meters(flange_thickness),
+            web_thickness: meters(web_thickness),
+            overhang: meters(overhang),
+            outer_radius: meters(0.0),
+            inner_radius: meters(0.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         }
     }
 
@@ -135,6 +538,21 @@ impl StructuralShape {
         width: f64,
         web_thickness: f64,
         flange_thickness: f64,
+    ) -> StructuralShape {
+        StructuralShape::new_rolled_ibeam(height, width, web_thickness, flange_thickness, 0.0)
+    }
+
+    /// Make a new Ibeam with root fillets without COG, such as a rolled section
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rolled_ibeam(0.3, 0.15, 0.0071, 0.0107, 0.015);
+    /// ```
+    pub fn new_rolled_ibeam(
+        height: f64,
+        width: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+        root_radius: f64,
     ) -> StructuralShape {
         StructuralShape::IBeam {
             width: meters(width),
@@ -142,255 +560,3405 @@ impl StructuralShape {
             web_thickness: meters(web_thickness),
             center_of_gravity: (meters(0.0), meters(0.0)),
             flange_thickness: meters(flange_thickness),
+            root_radius: meters(root_radius),
+            flange_slope: 0.0,
+            rotation: radians(0.0),
         }
     }
 
-    /// This function returns the moment of inertia of the structural shape around the x-axis
+    /// Make a new Ibeam with sloped flanges without COG, such as an American S shape. The flange
+    /// thickness is taken halfway along the sloped inside face of a flange, and the slope is its
+    /// rise over run.
     /// ```
-    /// # use structural_shapes::{StructuralShape};
-    /// let shape = StructuralShape::new_rod(2.0);
-    /// let moi = shape.moi_x();
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_tapered_ibeam(0.254, 0.125, 0.015, 0.0125, 1.0 / 6.0);
     /// ```
-    pub fn moi_x(&self) -> SecondAreaMomentofInertia {
-        match *self {
-            StructuralShape::Pipe {
-                outer_radius,
-                thickness,
-                center_of_gravity,
-            } => CompositeShape::new()
-                .add(StructuralShape::Rod {
-                    radius: outer_radius,
-                    center_of_gravity,
-                })
-                .sub(StructuralShape::Rod {
-                    radius: (outer_radius - thickness),
-                    center_of_gravity,
-                })
-                .moi_x(),
-            StructuralShape::IBeam {
-                width,
-                height,
-                flange_thickness,
-                web_thickness,
-                center_of_gravity,
-            } => composite_ibeam(
-                width,
-                height,
-                web_thickness,
-                flange_thickness,
-                center_of_gravity,
-            )
-            .moi_y(),
-            StructuralShape::BoxBeam {
-                width,
-                height,
-                thickness,
-                center_of_gravity,
-            } => CompositeShape::new()
-                .add(StructuralShape::Rectangle {
-                    width,
-                    height,
-                    center_of_gravity,
-                })
-                .sub(StructuralShape::Rectangle {
-                    width: (width - 2.0 * thickness),
-                    height: (height - 2.0 * thickness),
-                    center_of_gravity,
-                })
-                .moi_x(),
-            StructuralShape::Rod {
-                radius,
-                center_of_gravity,
-            } => {
-                std::f64::consts::PI * radius * radius * radius * radius / 4.0
-                    + self.area() * center_of_gravity.0 * center_of_gravity.0
-            }
-            StructuralShape::Rectangle {
-                width,
-                height,
-                center_of_gravity,
-            } => {
-                width * height * height * height / 12.0
-                    + self.area() * center_of_gravity.0 * center_of_gravity.0
-            }
+    pub fn new_tapered_ibeam(
+        height: f64,
+        width: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+        flange_slope: f64,
+    ) -> StructuralShape {
+        StructuralShape::IBeam {
+            width: meters(width),
+            height: meters(height),
+            web_thickness: meters(web_thickness),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            flange_thickness: meters(flange_thickness),
+            root_radius: meters(0.0),
+            flange_slope,
+            rotation: radians(0.0),
         }
-        .into()
     }
 
-    /// This function returns the moment of inertia of hte structural shape around the y-axis
+    /// Make a new channel without COG
     /// ```
     /// # use structural_shapes::StructuralShape;
-    /// let shape = StructuralShape::new_rod(2.0);
-    /// let area = shape.moi_y();
+    /// let shape = StructuralShape::new_channel(0.3, 0.1, 0.01, 0.015);
     /// ```
-    pub fn moi_y(&self) -> SecondAreaMomentofInertia {
-        match *self {
-            StructuralShape::Pipe {
-                outer_radius,
-                thickness,
-                center_of_gravity,
-            } => StructuralShape::Pipe {
-                outer_radius,
-                thickness,
-                center_of_gravity: swap(center_of_gravity),
-            }
-            .moi_x(),
-
-            StructuralShape::IBeam {
-                height,
-                width,
-                flange_thickness,
-                web_thickness,
-                center_of_gravity,
-            } => composite_ibeam(
-                width,
-                height,
-                web_thickness,
-                flange_thickness,
-                center_of_gravity,
-            )
-            .moi_y(),
-            StructuralShape::BoxBeam {
-                width,
-                height,
-                thickness,
-                center_of_gravity,
-            } => StructuralShape::BoxBeam {
-                width: height,
-                height: width,
-                thickness,
-                center_of_gravity: swap(center_of_gravity),
-            }
-            .moi_x(),
-            StructuralShape::Rod {
-                radius,
-                center_of_gravity,
-            } => {
-                std::f64::consts::PI * radius * radius * radius * radius / 4.0
-                    + self.area() * center_of_gravity.1 * center_of_gravity.1
-            }
-            StructuralShape::Rectangle {
-                width,
-                height,
-                center_of_gravity,
-            } => {
-                width * height * height * height / 12.0
-                    + self.area() * center_of_gravity.1 * center_of_gravity.1
-            }
-        }
+    pub fn new_channel(
+        height: f64,
+        width: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+    ) -> StructuralShape {
+        StructuralShape::new_tapered_channel(height, width, web_thickness, flange_thickness, 0.0)
     }
 
-    /// This function returns the polar moment of inertia of the composite shape about the origin.
+    /// Make a new channel with sloped flanges without COG, such as an American C shape. The flange
+    /// thickness is taken halfway along the sloped inside face of a flange, and the slope is its
+    /// rise over run.
     /// ```
     /// # use structural_shapes::StructuralShape;
-    /// let shape = StructuralShape::new_rod(2.0);
-    /// let area = shape.polar_moi();
+    /// let shape = StructuralShape::new_tapered_channel(0.3, 0.075, 0.007, 0.0127, 1.0 / 6.0);
     /// ```
-    pub fn polar_moi(&self) -> SecondAreaMomentofInertia {
+    pub fn new_tapered_channel(
+        height: f64,
+        width: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+        flange_slope: f64,
+    ) -> StructuralShape {
+        StructuralShape::Channel {
+            width: meters(width),
+            height: meters(height),
+            web_thickness: meters(web_thickness),
+            flange_thickness: meters(flange_thickness),
+            flange_slope,
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new angle without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_angle(0.15, 0.1, 0.01);
+    /// ```
+    pub fn new_angle(height: f64, width: f64, thickness: f64) -> StructuralShape {
+        StructuralShape::Angle {
+            width: meters(width),
+            height: meters(height),
+            thickness: meters(thickness),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new tee without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_tee(0.15, 0.2, 0.01, 0.015);
+    /// ```
+    pub fn new_tee(
+        height: f64,
+        width: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+    ) -> StructuralShape {
+        StructuralShape::Tee {
+            width: meters(width),
+            height: meters(height),
+            web_thickness: meters(web_thickness),
+            flange_thickness: meters(flange_thickness),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new double tee without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_double_tee(0.6, 2.4, 0.05, 0.15, 1.2);
+    /// ```
+    pub fn new_double_tee(
+        height: f64,
+        width: f64,
+        flange_thickness: f64,
+        stem_width: f64,
+        stem_spacing: f64,
+    ) -> StructuralShape {
+        StructuralShape::DoubleTee {
+            width: meters(width),
+            height: meters(height),
+            flange_thickness: meters(flange_thickness),
+            stem_width: meters(stem_width),
+            stem_spacing: meters(stem_spacing),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new lipped channel without COG, from centerline dimensions
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_lipped_channel(0.2, 0.07, 0.02, 0.002, 0.003);
+    /// ```
+    pub fn new_lipped_channel(
+        height: f64,
+        width: f64,
+        lip: f64,
+        thickness: f64,
+        bend_radius: f64,
+    ) -> StructuralShape {
+        StructuralShape::LippedChannel {
+            width: meters(width),
+            height: meters(height),
+            lip: meters(lip),
+            thickness: meters(thickness),
+            bend_radius: meters(bend_radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new lipped Z-section without COG, from centerline dimensions
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_lipped_z(0.2, 0.07, 0.02, 0.002, 0.003);
+    /// ```
+    pub fn new_lipped_z(
+        height: f64,
+        width: f64,
+        lip: f64,
+        thickness: f64,
+        bend_radius: f64,
+    ) -> StructuralShape {
+        StructuralShape::LippedZ {
+            width: meters(width),
+            height: meters(height),
+            lip: meters(lip),
+            thickness: meters(thickness),
+            bend_radius: meters(bend_radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new sigma section without COG, from centerline dimensions
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_sigma(0.2, 0.062, 0.02, 0.045, 0.015, 0.06, 0.002, 0.003);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_sigma(
+        height: f64,
+        width: f64,
+        lip: f64,
+        web_flat: f64,
+        indent: f64,
+        indent_height: f64,
+        thickness: f64,
+        bend_radius: f64,
+    ) -> StructuralShape {
+        StructuralShape::Sigma {
+            width: meters(width),
+            height: meters(height),
+            lip: meters(lip),
+            web_flat: meters(web_flat),
+            indent: meters(indent),
+            indent_height: meters(indent_height),
+            thickness: meters(thickness),
+            bend_radius: meters(bend_radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new hat section without COG, from centerline dimensions
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_hat(0.05, 0.05, 0.03, 0.001, 0.002);
+    /// ```
+    pub fn new_hat(
+        height: f64,
+        width: f64,
+        flange: f64,
+        thickness: f64,
+        bend_radius: f64,
+    ) -> StructuralShape {
+        StructuralShape::Hat {
+            width: meters(width),
+            height: meters(height),
+            flange: meters(flange),
+            thickness: meters(thickness),
+            bend_radius: meters(bend_radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new ellipse without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ellipse(0.3, 0.15);
+    /// ```
+    pub fn new_ellipse(height: f64, width: f64) -> StructuralShape {
+        StructuralShape::Ellipse {
+            width: meters(width),
+            height: meters(height),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new elliptical hollow section without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_elliptical_hollow(0.3, 0.15, 0.008);
+    /// ```
+    pub fn new_elliptical_hollow(height: f64, width: f64, thickness: f64) -> StructuralShape {
+        StructuralShape::EllipticalHollow {
+            width: meters(width),
+            height: meters(height),
+            thickness: meters(thickness),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new triangle without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// // A right triangle, with its apex above the left end of its base
+    /// let shape = StructuralShape::new_triangle(0.3, 0.2, 0.0);
+    /// ```
+    pub fn new_triangle(height: f64, width: f64, apex: f64) -> StructuralShape {
+        StructuralShape::Triangle {
+            width: meters(width),
+            height: meters(height),
+            apex: meters(apex),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new trapezoid without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// // A symmetric trapezoid
+    /// let shape = StructuralShape::new_trapezoid(0.1, 0.3, 0.2, 0.05);
+    /// ```
+    pub fn new_trapezoid(
+        height: f64,
+        bottom_width: f64,
+        top_width: f64,
+        top_offset: f64,
+    ) -> StructuralShape {
+        StructuralShape::Trapezoid {
+            bottom_width: meters(bottom_width),
+            top_width: meters(top_width),
+            height: meters(height),
+            top_offset: meters(top_offset),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new semicircle without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_semicircle(0.1);
+    /// ```
+    pub fn new_semicircle(radius: f64) -> StructuralShape {
+        StructuralShape::Semicircle {
+            radius: meters(radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new circular segment without COG, with the angle subtended by its arc in radians
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_circular_segment(0.1, std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn new_circular_segment(radius: f64, angle: f64) -> StructuralShape {
+        StructuralShape::CircularSegment {
+            radius: meters(radius),
+            angle: radians(angle),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new circular sector without COG, with the angle between its straight edges in radians
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_circular_sector(0.1, std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn new_circular_sector(radius: f64, angle: f64) -> StructuralShape {
+        StructuralShape::CircularSector {
+            radius: meters(radius),
+            angle: radians(angle),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new annular sector without COG, with the angle subtended by its arcs in radians
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_annular_sector(1.0, 0.02, 0.5);
+    /// ```
+    pub fn new_annular_sector(outer_radius: f64, thickness: f64, angle: f64) -> StructuralShape {
+        StructuralShape::AnnularSector {
+            outer_radius: meters(outer_radius),
+            thickness: meters(thickness),
+            angle: radians(angle),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new plate girder without COG
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// // A crane girder with a wider top flange
+    /// let shape = StructuralShape::new_plate_girder(1.0, 0.012, 0.4, 0.03, 0.3, 0.02);
+    /// ```
+    pub fn new_plate_girder(
+        height: f64,
+        web_thickness: f64,
+        top_width: f64,
+        top_thickness: f64,
+        bottom_width: f64,
+        bottom_thickness: f64,
+    ) -> StructuralShape {
+        StructuralShape::PlateGirder {
+            height: meters(height),
+            web_thickness: meters(web_thickness),
+            top_width: meters(top_width),
+            top_thickness: meters(top_thickness),
+            bottom_width: meters(bottom_width),
+            bottom_thickness: meters(bottom_thickness),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new regular polygon without COG, from its number of sides and the distance from its
+    /// center to each corner. This panics if there are fewer than three sides.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_regular_polygon(6, 0.1);
+    /// ```
+    pub fn new_regular_polygon(sides: usize, radius: f64) -> StructuralShape {
+        assert!(
+            sides >= 3,
+            "A regular polygon must have at least three sides."
+        );
+        StructuralShape::RegularPolygon {
+            sides,
+            radius: meters(radius),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new regular polygon without COG, from its number of sides and the distance across
+    /// its flats. With an odd number of sides, this is twice the distance from the center to a
+    /// flat. This panics if there are fewer than three sides.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_regular_polygon_across_flats(8, 0.2);
+    /// ```
+    pub fn new_regular_polygon_across_flats(sides: usize, across_flats: f64) -> StructuralShape {
+        StructuralShape::new_regular_polygon(sides, circumradius(sides, across_flats))
+    }
+
+    /// Make a new hollow regular polygon without COG, from its number of sides, the distance from
+    /// its center to each outside corner and the thickness of its wall. This panics if there are
+    /// fewer than three sides, or if the wall is not thinner than the distance from the center to
+    /// the outside flats.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_hollow_regular_polygon(12, 0.15, 0.005);
+    /// ```
+    pub fn new_hollow_regular_polygon(
+        sides: usize,
+        radius: f64,
+        thickness: f64,
+    ) -> StructuralShape {
+        assert!(
+            sides >= 3,
+            "A regular polygon must have at least three sides."
+        );
+        let apothem = radius * (std::f64::consts::PI / sides as f64).cos();
+        assert!(
+            thickness < apothem,
+            "The wall must be thinner than the distance from the center to the flats, {}.",
+            apothem
+        );
+        StructuralShape::HollowRegularPolygon {
+            sides,
+            radius: meters(radius),
+            thickness: meters(thickness),
+            center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// Make a new hollow regular polygon without COG, from its number of sides, the distance
+    /// across its outside flats and the thickness of its wall. This panics in the same cases as
+    /// `new_hollow_regular_polygon`.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_hollow_regular_polygon_across_flats(8, 0.3, 0.006);
+    /// ```
+    pub fn new_hollow_regular_polygon_across_flats(
+        sides: usize,
+        across_flats: f64,
+        thickness: f64,
+    ) -> StructuralShape {
+        StructuralShape::new_hollow_regular_polygon(
+            sides,
+            circumradius(sides, across_flats),
+            thickness,
+        )
+    }
+
+    /// Look up a standard shape by its designation in the bundled catalogs, such as `W12X26`,
+    /// `HSS8X4X1/2`, `IPE 300` or `RHS 200x100x8`, ignoring case and spaces. Shapes that are not
+    /// in a catalog return `None`.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::from_designation("W12X26").unwrap();
+    /// let shape = StructuralShape::from_designation("HEB 200").unwrap();
+    /// ```
+    pub fn from_designation(designation: &str) -> Option<StructuralShape> {
+        catalog::aisc::lookup(designation).or_else(|| catalog::european::lookup(designation))
+    }
+
+    /// Cut an I-beam through the middle of its web to make a tee without COG, such as a WT from a
+    /// W-shape. The tee has no root fillets, and a sloped flange becomes a parallel one of the same
+    /// thickness. Other shapes return `None`.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.3, 0.2, 0.01, 0.015).cut_tee();
+    /// assert!(shape.is_some());
+    /// ```
+    pub fn cut_tee(&self) -> Option<StructuralShape> {
+        match *self {
+            StructuralShape::IBeam {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => Some(StructuralShape::Tee {
+                width,
+                height: height / 2.0,
+                web_thickness,
+                flange_thickness,
+                center_of_gravity: (meters(0.0), meters(0.0)),
+                rotation: radians(0.0),
+            }),
+            _ => None,
+        }
+    }
+
+    /// This function returns the moment of inertia of the structural shape around the x-axis,
+    /// adding `A·y²` for the height of the center of gravity. Version 0.2 added `A·x²` instead.
+    /// ```
+    /// # use structural_shapes::{StructuralShape};
+    /// let shape = StructuralShape::new_rod(2.0);
+    /// let moi = shape.moi_x();
+    /// ```
+    pub fn moi_x(&self) -> SecondAreaMomentofInertia {
+        let (_, cy) = self.get_cog();
+        self.centroidal_moi_x() + self.area() * cy * cy
+    }
+
+    /// This function returns the moment of inertia of hte structural shape around the y-axis,
+    /// adding `A·x²` for the horizontal offset of the center of gravity. Version 0.2 added `A·y²`
+    /// instead.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rod(2.0);
+    /// let area = shape.moi_y();
+    /// ```
+    pub fn moi_y(&self) -> SecondAreaMomentofInertia {
+        let (cx, _) = self.get_cog();
+        self.centroidal_moi_y() + self.area() * cx * cx
+    }
+
+    /// This function returns the product moment of inertia of the structural shape about the x- and y-axes
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(2.0, 1.0).with_cog(1.0, 2.0);
+    /// let ixy = shape.product_moi();
+    /// ```
+    pub fn product_moi(&self) -> SecondAreaMomentofInertia {
+        let (cx, cy) = self.get_cog();
+        self.centroidal_product_moi() + self.area() * cx * cy
+    }
+
+    /// This function returns the moment of inertia of the structural shape about the horizontal
+    /// axis through its center of gravity
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(0.0, 1.0);
+    /// let moi = shape.centroidal_moi_x();
+    /// ```
+    pub fn centroidal_moi_x(&self) -> SecondAreaMomentofInertia {
+        self.centroidal_moments().0
+    }
+
+    /// This function returns the moment of inertia of the structural shape about the vertical
+    /// axis through its center of gravity
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(1.0, 0.0);
+    /// let moi = shape.centroidal_moi_y();
+    /// ```
+    pub fn centroidal_moi_y(&self) -> SecondAreaMomentofInertia {
+        self.centroidal_moments().1
+    }
+
+    /// This function returns the product moment of inertia of the structural shape about the
+    /// axes through its center of gravity
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(2.0, 1.0).with_rotation(0.5);
+    /// let ixy = shape.centroidal_product_moi();
+    /// ```
+    pub fn centroidal_product_moi(&self) -> SecondAreaMomentofInertia {
+        self.centroidal_moments().2
+    }
+
+    /// This function returns the moment of inertia of the structural shape about an axis through
+    /// `point`, inclined at `angle` counterclockwise from the x-axis
+    /// ```
+    /// # use structural_shapes::{degrees, meters, StructuralShape};
+    /// let shape = StructuralShape::new_rectangle(2.0, 1.0);
+    /// let moi = shape.moi_about((meters(0.0), meters(1.0)), degrees(30.0));
+    /// ```
+    pub fn moi_about(&self, point: (Length, Length), angle: Angle) -> SecondAreaMomentofInertia {
+        let (ix, iy, ixy) = self.centroidal_moments();
+        moi_about(self.area(), self.get_cog(), (ix, iy, ixy), point, angle)
+    }
+
+    /// This function returns the polar moment of inertia of the composite shape about the origin.
+    /// This only equals the torsion constant for rods and pipes; use `torsion_constant` for
+    /// torsional stiffness.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rod(2.0);
+    /// let area = shape.polar_moi();
+    /// ```
+    pub fn polar_moi(&self) -> SecondAreaMomentofInertia {
         self.moi_x() + self.moi_y()
     }
 
-    /// This function returns the cross-sectional area of the structural shape
+    /// This function returns the Saint-Venant torsion constant of the structural shape. Rods,
+    /// pipes and ellipses are exact, rectangles use Roark's approximation, I-beams with root
+    /// fillets use El Darwish and Johnston's formula, other I-beams, plate girders, channels,
+    /// angles, tees, cold-formed shapes and annular sectors are treated as open thin-walled
+    /// sections, box beams (around their rounded corners, with any overhangs as outstands),
+    /// elliptical hollow sections and hollow regular polygons as closed thin-walled sections
+    /// (Bredt-Batho), and regular polygons, triangles, trapezoids, semicircles and circular
+    /// segments and sectors use Saint-Venant's approximation for compact solid sections. Sloped
+    /// flanges are taken at their average thickness. Channels leave out their root fillets, so
+    /// rolled channels come out about 10% below published tables, such as 0.332 in⁴ against
+    /// AISC's 0.369 in⁴ for a C12X20.7.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let j = shape.torsion_constant();
+    /// ```
+    pub fn torsion_constant(&self) -> SecondAreaMomentofInertia {
+        match *self {
+            StructuralShape::Pipe {
+                outer_radius,
+                thickness,
+                ..
+            } => {
+                let inner_radius = outer_radius - thickness;
+                std::f64::consts::PI
+                    * (outer_radius * outer_radius * outer_radius * outer_radius
+                        - inner_radius * inner_radius * inner_radius * inner_radius)
+                    / 2.0
+            }
+            StructuralShape::IBeam {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                root_radius,
+                ..
+            } if root_radius.value > 0.0 => {
+                // El Darwish and Johnston's formula for rolled sections, including the fillets
+                let (tf, tw, r) = (flange_thickness, web_thickness, root_radius);
+                let alpha = -0.042 + 0.2204 * (tw / tf).value + 0.1355 * (r / tf).value
+                    - 0.0865 * (r * tw / (tf * tf)).value
+                    - 0.0725 * (tw * tw / (tf * tf)).value;
+                let d = ((tf + r) * (tf + r) + tw * (r + tw / 4.0)) / (2.0 * r + tf);
+                2.0 / 3.0 * width * tf * tf * tf
+                    + (height - 2.0 * tf) * tw * tw * tw / 3.0
+                    + 2.0 * alpha * d * d * d * d
+                    - 0.420 * tf * tf * tf * tf
+            }
+            StructuralShape::IBeam {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            }
+            | StructuralShape::Channel {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                (2.0 * width * flange_thickness * flange_thickness * flange_thickness
+                    + (height - flange_thickness) * web_thickness * web_thickness * web_thickness)
+                    / 3.0
+            }
+            StructuralShape::BoxBeam {
+                width,
+                height,
+                flange_thickness,
+                web_thickness,
+                overhang,
+                outer_radius,
+                inner_radius,
+                ..
+            } => {
+                // Along the midline of the walls, which turns the corners at the mean radius and
+                // mean thickness, with the overhangs as open outstands
+                let (tf, tw) = (flange_thickness, web_thickness);
+                let (radius, corner) = ((outer_radius + inner_radius) / 2.0, (tf + tw) / 2.0);
+                let flange = width - 2.0 * overhang - tw - 2.0 * radius;
+                let web = height - tf - 2.0 * radius;
+                let arcs = 2.0 * std::f64::consts::PI * radius;
+                let enclosed_area = (width - 2.0 * overhang - tw) * (height - tf)
+                    - (4.0 - std::f64::consts::PI) * radius * radius;
+                let flexibility = 2.0 * flange / tf + 2.0 * web / tw + arcs / corner;
+                (2.0 * (flange + 2.0 * overhang) * tf * tf * tf
+                    + 2.0 * web * tw * tw * tw
+                    + arcs * corner * corner * corner)
+                    / 3.0
+                    + 4.0 * enclosed_area * enclosed_area / flexibility
+            }
+            StructuralShape::Rod { radius, .. } => {
+                std::f64::consts::PI * radius * radius * radius * radius / 2.0
+            }
+            StructuralShape::Ellipse { width, height, .. } => {
+                let (a, b) = (width / 2.0, height / 2.0);
+                std::f64::consts::PI * a * a * a * b * b * b / (a * a + b * b)
+            }
+            StructuralShape::EllipticalHollow {
+                width,
+                height,
+                thickness,
+                ..
+            } => {
+                // Closed thin-walled section along the midline, with Ramanujan's perimeter
+                let (a, b) = ((width - thickness) / 2.0, (height - thickness) / 2.0);
+                let perimeter = std::f64::consts::PI
+                    * meters(
+                        3.0 * (a + b).value - ((3.0 * a + b).value * (a + 3.0 * b).value).sqrt(),
+                    );
+                let enclosed_area = std::f64::consts::PI * a * b;
+                thickness * thickness * thickness * perimeter / 3.0
+                    + 4.0 * enclosed_area * enclosed_area * thickness / perimeter
+            }
+            StructuralShape::Rectangle { width, height, .. } => {
+                let (long, short) = if width > height {
+                    (width, height)
+                } else {
+                    (height, width)
+                };
+                let ratio = (short / long).value;
+                long * short
+                    * short
+                    * short
+                    * (1.0 / 3.0 - 0.21 * ratio * (1.0 - ratio * ratio * ratio * ratio / 12.0))
+            }
+            StructuralShape::Angle {
+                width,
+                height,
+                thickness,
+                ..
+            } => (width + height - thickness) * thickness * thickness * thickness / 3.0,
+            StructuralShape::Tee {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                (width * flange_thickness * flange_thickness * flange_thickness
+                    + (height - flange_thickness / 2.0)
+                        * web_thickness
+                        * web_thickness
+                        * web_thickness)
+                    / 3.0
+            }
+            StructuralShape::DoubleTee {
+                width,
+                height,
+                flange_thickness,
+                stem_width,
+                ..
+            } => {
+                (width * flange_thickness * flange_thickness * flange_thickness
+                    + 2.0
+                        * (height - flange_thickness / 2.0)
+                        * stem_width
+                        * stem_width
+                        * stem_width)
+                    / 3.0
+            }
+            StructuralShape::PlateGirder {
+                height,
+                web_thickness,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                let web = height - (top_thickness + bottom_thickness) / 2.0;
+                (top_width * top_thickness * top_thickness * top_thickness
+                    + bottom_width * bottom_thickness * bottom_thickness * bottom_thickness
+                    + web * web_thickness * web_thickness * web_thickness)
+                    / 3.0
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. }
+            | StructuralShape::AnnularSector { .. } => {
+                meters_to_the_fourth(self.wall_analysis().torsion_constant)
+            }
+            StructuralShape::HollowRegularPolygon {
+                sides,
+                radius,
+                thickness,
+                ..
+            } => {
+                // Closed thin-walled section along the midline
+                let angle = std::f64::consts::PI / sides as f64;
+                let midline = radius - thickness / (2.0 * angle.cos());
+                let perimeter = 2.0 * sides as f64 * midline * angle.sin();
+                let enclosed_area = sides as f64 * midline * midline * (2.0 * angle).sin() / 2.0;
+                thickness * thickness * thickness * perimeter / 3.0
+                    + 4.0 * enclosed_area * enclosed_area * thickness / perimeter
+            }
+            StructuralShape::RegularPolygon { .. }
+            | StructuralShape::Triangle { .. }
+            | StructuralShape::Trapezoid { .. }
+            | StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. } => {
+                // Saint-Venant's approximation for compact solid sections
+                let (ix, iy, _) = self.local_moments();
+                compact_torsion(self.area(), ix, iy)
+            }
+        }
+    }
+
+    /// This function returns the warping constant of the structural shape. I-beams, plate girders,
+    /// channels, angles and tees use thin-walled formulas, cold-formed shapes and annular sectors
+    /// integrate the sectorial coordinates along their centerline, and box beams use the
+    /// closed thin-walled result, or also integrate along their centerline when their flanges
+    /// overhang. Rods, pipes and hollow regular polygons do not warp, and regular polygons,
+    /// ellipses, triangles, trapezoids, semicircles and circular segments and sectors are treated
+    /// as compact sections that do not warp either, as are elliptical hollow sections, whose
+    /// warping is slight. Rectangles use the narrow-rectangle approximation. Sloped flanges are
+    /// taken at their average thickness, as they are for the shear center of a channel.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let cw = shape.warping_constant();
+    /// ```
+    pub fn warping_constant(&self) -> WarpingConstant {
+        match *self {
+            StructuralShape::Pipe { .. }
+            | StructuralShape::Rod { .. }
+            | StructuralShape::Triangle { .. }
+            | StructuralShape::Trapezoid { .. }
+            | StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. }
+            | StructuralShape::Ellipse { .. }
+            | StructuralShape::EllipticalHollow { .. }
+            | StructuralShape::RegularPolygon { .. }
+            | StructuralShape::HollowRegularPolygon { .. } => WarpingConstant::default(),
+            StructuralShape::IBeam {
+                width,
+                height,
+                flange_thickness,
+                ..
+            } => {
+                let flange_distance = height - flange_thickness;
+                flange_thickness * width * width * width * flange_distance * flange_distance / 24.0
+            }
+            StructuralShape::BoxBeam {
+                width,
+                height,
+                flange_thickness,
+                web_thickness,
+                overhang,
+                ..
+            } if overhang.value <= 0.0 => {
+                let (b, h) = (width - web_thickness, height - flange_thickness);
+                let (tf, tw) = (flange_thickness, web_thickness);
+                let skew = h * tf - b * tw;
+                let stiffness = b * tw + h * tf;
+                b * b * h * h * skew * skew * (b * tf + h * tw) / (24.0 * stiffness * stiffness)
+            }
+            StructuralShape::Rectangle { width, height, .. } => {
+                width * width * width * height * height * height / 144.0
+            }
+            StructuralShape::Channel {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                let (b, h) = (width - web_thickness / 2.0, height - flange_thickness);
+                flange_thickness * b * b * b * h * h / 12.0
+                    * (3.0 * b * flange_thickness + 2.0 * h * web_thickness)
+                    / (6.0 * b * flange_thickness + h * web_thickness)
+            }
+            StructuralShape::Angle {
+                width,
+                height,
+                thickness,
+                ..
+            } => {
+                // Only the small through-thickness term remains, as the leg centerlines meet at
+                // the shear center
+                let (b, h) = (width - thickness / 2.0, height - thickness / 2.0);
+                thickness * thickness * thickness * (b * b * b + h * h * h) / 36.0
+            }
+            StructuralShape::Tee {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                // As for the angle, the flange and stem centerlines meet at the shear center
+                let stem = height - flange_thickness / 2.0;
+                let (flange_cube, web_cube) = (
+                    flange_thickness * flange_thickness * flange_thickness,
+                    web_thickness * web_thickness * web_thickness,
+                );
+                flange_cube * width * width * width / 144.0 + web_cube * stem * stem * stem / 36.0
+            }
+            StructuralShape::DoubleTee {
+                width,
+                flange_thickness,
+                stem_width,
+                stem_spacing,
+                ..
+            } => {
+                let stem = self.double_tee_stem();
+                let eccentricity = self.double_tee_eccentricity();
+                let below = stem - eccentricity;
+                flange_thickness * eccentricity * eccentricity * width * width * width / 12.0
+                    + stem_width
+                        * stem_spacing
+                        * stem_spacing
+                        * (eccentricity * eccentricity * eccentricity + below * below * below)
+                        / 6.0
+            }
+            StructuralShape::PlateGirder {
+                height,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                let flange_distance = height - (top_thickness + bottom_thickness) / 2.0;
+                let top = top_thickness * top_width * top_width * top_width / 12.0;
+                let bottom = bottom_thickness * bottom_width * bottom_width * bottom_width / 12.0;
+                flange_distance * flange_distance * top * bottom / (top + bottom)
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. }
+            | StructuralShape::BoxBeam { .. }
+            | StructuralShape::AnnularSector { .. } => {
+                meters_to_the_sixth(self.wall_analysis().warping_constant)
+            }
+        }
+    }
+
+    /// This function returns the coordinates of the shear center of the structural shape
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(1.0, 0.0);
+    /// let (x, y) = shape.shear_center();
+    /// ```
+    pub fn shear_center(&self) -> (Length, Length) {
+        let local = match *self {
+            StructuralShape::Channel {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                // Measured from the web centerline, away from the flanges
+                let (b, h) = (width - web_thickness / 2.0, height - flange_thickness);
+                let eccentricity = 3.0 * b * b * flange_thickness
+                    / (6.0 * b * flange_thickness + h * web_thickness);
+                let (left, _) = self.centroid_offset();
+                (web_thickness / 2.0 - left - eccentricity, Length::default())
+            }
+            StructuralShape::Angle { thickness, .. } => {
+                // Where the leg centerlines meet
+                let (left, bottom) = self.centroid_offset();
+                (thickness / 2.0 - left, thickness / 2.0 - bottom)
+            }
+            StructuralShape::Tee {
+                height,
+                flange_thickness,
+                ..
+            } => {
+                // Where the flange and stem centerlines meet
+                let (_, bottom) = self.centroid_offset();
+                (Length::default(), height - flange_thickness / 2.0 - bottom)
+            }
+            StructuralShape::DoubleTee {
+                height,
+                flange_thickness,
+                ..
+            } => {
+                // Above the flange centerline, away from the stems
+                let (_, bottom) = self.centroid_offset();
+                (
+                    Length::default(),
+                    height - flange_thickness / 2.0 - bottom + self.double_tee_eccentricity(),
+                )
+            }
+            StructuralShape::PlateGirder {
+                height,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                // Between the flange centerlines, nearer the stiffer flange
+                let flange_distance = height - (top_thickness + bottom_thickness) / 2.0;
+                let top = top_thickness * top_width * top_width * top_width;
+                let bottom = bottom_thickness * bottom_width * bottom_width * bottom_width;
+                let (_, centroid) = self.centroid_offset();
+                (
+                    Length::default(),
+                    bottom_thickness / 2.0 + flange_distance * top / (top + bottom) - centroid,
+                )
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. } => {
+                let [x, y] = self.wall_analysis().shear_center;
+                let (_, [cx, cy]) = self.profile_outline();
+                (meters(x - cx), meters(y - cy))
+            }
+            StructuralShape::AnnularSector { .. } => {
+                let [x, y] = self.wall_analysis().shear_center;
+                (meters(x), meters(y))
+            }
+            // The remaining shapes are doubly symmetric, or taken to be compact
+            _ => (Length::default(), Length::default()),
+        };
+        offset(self.get_cog(), self.get_rotation(), local)
+    }
+
+    /// This function returns the monosymmetry parameter (Wagner coefficient) for bending about the
+    /// centroidal x-axis of the unrotated shape. This is βx = 2y0 - ∫y(x² + y²)dA / Ix, where y is
+    /// measured up from the center of gravity and y0 is the height of the shear center above it,
+    /// so it is positive when the larger flange is on top and zero for shapes that are symmetric
+    /// about the x-axis. Angles have no axis of symmetry, so for them this is AISC's βw for bending
+    /// about the major principal axis w, which has the opposite sign: βw = ∫z(w² + z²)dA / Iw - 2z0,
+    /// where z is measured along the minor principal axis, a quarter turn counterclockwise from w,
+    /// and z0 is the offset of the shear center along it. This matches the positive values that
+    /// AISC tables list for angles with the long leg vertical, as built by `new_angle`.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let beta_x = shape.monosymmetry_parameter();
+    /// ```
+    pub fn monosymmetry_parameter(&self) -> Length {
+        match *self {
+            StructuralShape::Pipe { .. }
+            | StructuralShape::IBeam { .. }
+            | StructuralShape::BoxBeam { .. }
+            | StructuralShape::Rod { .. }
+            | StructuralShape::Rectangle { .. }
+            | StructuralShape::Channel { .. }
+            | StructuralShape::LippedChannel { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Ellipse { .. }
+            | StructuralShape::EllipticalHollow { .. } => Length::default(),
+            // Z-sections are symmetric about their center of gravity, which is also their shear
+            // center, so the Wagner integral vanishes
+            StructuralShape::LippedZ { .. } => Length::default(),
+            _ => {
+                // Work in the frame of the unrotated shape, or of its principal axes for angles
+                let (ix, iy, ixy) = self.local_moments();
+                let (angle, major) = match *self {
+                    StructuralShape::Angle { .. } => {
+                        let (angle, major, _) = principal_axes(ix, iy, ixy);
+                        (angle, major)
+                    }
+                    _ => (Angle::default(), ix),
+                };
+                let boundary: Vec<geometry::Edge> = self
+                    .local_boundary()
+                    .iter()
+                    .map(|edge| edge.place(-angle.value, [0.0, 0.0]))
+                    .collect();
+                let (x0, y0) = self.shear_center();
+                let (cx, cy) = self.get_cog();
+                let shear_center = geometry::place(
+                    [(x0 - cx).value, (y0 - cy).value],
+                    -(angle + self.get_rotation()).value,
+                    [0.0, 0.0],
+                );
+                let beta = wagner(&boundary, major, shear_center);
+                match *self {
+                    StructuralShape::Angle { .. } => -beta,
+                    _ => beta,
+                }
+            }
+        }
+    }
+
+    /// This function returns the angle from the x-axis to the major principal axis, measured
+    /// counterclockwise about the center of gravity
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(1.0, 2.0);
+    /// let angle = shape.principal_angle();
+    /// ```
+    pub fn principal_angle(&self) -> Angle {
+        let (ix, iy, ixy) = self.centroidal_moments();
+        principal_axes(ix, iy, ixy).0
+    }
+
+    /// This function returns the major and minor principal moments of inertia about the center of gravity
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(1.0, 2.0);
+    /// let (i1, i2) = shape.principal_moi();
+    /// ```
+    pub fn principal_moi(&self) -> (SecondAreaMomentofInertia, SecondAreaMomentofInertia) {
+        let (ix, iy, ixy) = self.centroidal_moments();
+        let (_, i1, i2) = principal_axes(ix, iy, ixy);
+        (i1, i2)
+    }
+
+    /// This function returns the radius of gyration about the centroidal x-axis
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_pipe(0.1, 0.01);
+    /// let rx = shape.radius_of_gyration_x();
+    /// ```
+    pub fn radius_of_gyration_x(&self) -> Length {
+        let (ix, _, _) = self.centroidal_moments();
+        (ix / self.area()).sqrt()
+    }
+
+    /// This function returns the radius of gyration about the centroidal y-axis
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_pipe(0.1, 0.01);
+    /// let ry = shape.radius_of_gyration_y();
+    /// ```
+    pub fn radius_of_gyration_y(&self) -> Length {
+        let (_, iy, _) = self.centroidal_moments();
+        (iy / self.area()).sqrt()
+    }
+
+    /// This function returns the least radius of gyration, about the minor principal axis
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let r_min = shape.radius_of_gyration_min();
+    /// ```
+    pub fn radius_of_gyration_min(&self) -> Length {
+        (self.principal_moi().1 / self.area()).sqrt()
+    }
+
+    /// This function returns the radii of gyration about the major and minor principal axes, such
+    /// as the w-w and z-z axes of an angle
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_angle(0.15, 0.1, 0.01);
+    /// let (r_major, r_minor) = shape.principal_radius_of_gyration();
+    /// ```
+    pub fn principal_radius_of_gyration(&self) -> (Length, Length) {
+        let (i1, i2) = self.principal_moi();
+        ((i1 / self.area()).sqrt(), (i2 / self.area()).sqrt())
+    }
+
+    /// This function returns the slenderness ratio KL/r for an effective length KL, using the least
+    /// radius of gyration
+    /// ```
+    /// # use structural_shapes::{meters, StructuralShape};
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let slenderness = shape.slenderness(meters(3.0));
+    /// ```
+    pub fn slenderness(&self, effective_length: Length) -> f64 {
+        (effective_length / self.radius_of_gyration_min()).value
+    }
+
+    /// Moments and product of inertia about axes through the center of gravity
+    fn centroidal_moments(
+        &self,
+    ) -> (
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+    ) {
+        rotate_moments(self.local_moments(), self.get_rotation())
+    }
+
+    /// Moments and product of inertia about the centroidal axes of the unrotated shape
+    fn local_moments(
+        &self,
+    ) -> (
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+    ) {
+        // Every shape here except the angle and Z-section is symmetric about one of its own
+        // centroidal axes
+        let product = SecondAreaMomentofInertia::default();
+        match *self {
+            StructuralShape::Pipe {
+                outer_radius,
+                thickness,
+                ..
+            } => {
+                let inner_radius = outer_radius - thickness;
+                let moi = std::f64::consts::PI
+                    * (outer_radius * outer_radius * outer_radius * outer_radius
+                        - inner_radius * inner_radius * inner_radius * inner_radius)
+                    / 4.0;
+                (moi, moi, product)
+            }
+            StructuralShape::IBeam { flange_slope, .. }
+            | StructuralShape::Channel { flange_slope, .. }
+                if flange_slope != 0.0 =>
+            {
+                edge_moments(&self.local_boundary())
+            }
+            StructuralShape::IBeam {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                root_radius,
+                ..
+            } => {
+                let web_height = height - 2.0 * flange_thickness;
+                // The four fillets, each in a corner between the web and a flange
+                let (fillet, distance, moi) = spandrel(root_radius);
+                let (x, y) = (web_thickness / 2.0 + distance, web_height / 2.0 - distance);
+                (
+                    (width * height * height * height
+                        - (width - web_thickness) * web_height * web_height * web_height)
+                        / 12.0
+                        + 4.0 * (moi + fillet * y * y),
+                    (2.0 * flange_thickness * width * width * width
+                        + web_height * web_thickness * web_thickness * web_thickness)
+                        / 12.0
+                        + 4.0 * (moi + fillet * x * x),
+                    product,
+                )
+            }
+            StructuralShape::BoxBeam {
+                width,
+                height,
+                flange_thickness,
+                web_thickness,
+                overhang,
+                outer_radius,
+                inner_radius,
+                ..
+            } => {
+                let (inner_width, inner_height) = (
+                    width - 2.0 * (overhang + web_thickness),
+                    height - 2.0 * flange_thickness,
+                );
+                // Between the webs, and outside them under the overhangs
+                let (between, outside) = (width - 2.0 * web_thickness, width - 2.0 * overhang);
+                // Less the material rounded off the outside corners, plus that left in the inside
+                // corners of the hole
+                let (outer, outer_distance, outer_moi) = spandrel(outer_radius);
+                let (inner, inner_distance, inner_moi) = spandrel(inner_radius);
+                let corners = |outer_offset: Length, inner_offset: Length| {
+                    4.0 * (inner_moi + inner * inner_offset * inner_offset
+                        - outer_moi
+                        - outer * outer_offset * outer_offset)
+                };
+                (
+                    (width * height * height * height
+                        - between * inner_height * inner_height * inner_height)
+                        / 12.0
+                        + corners(
+                            height / 2.0 - outer_distance,
+                            inner_height / 2.0 - inner_distance,
+                        ),
+                    (height * width * width * width
+                        - inner_height
+                            * (width * width * width - outside * outside * outside
+                                + inner_width * inner_width * inner_width))
+                        / 12.0
+                        + corners(
+                            width / 2.0 - outer_distance,
+                            inner_width / 2.0 - inner_distance,
+                        ),
+                    product,
+                )
+            }
+            StructuralShape::Rod { radius, .. } => {
+                let moi = std::f64::consts::PI * radius * radius * radius * radius / 4.0;
+                (moi, moi, product)
+            }
+            StructuralShape::Ellipse { width, height, .. } => {
+                let (a, b) = (width / 2.0, height / 2.0);
+                (
+                    std::f64::consts::PI * a * b * b * b / 4.0,
+                    std::f64::consts::PI * a * a * a * b / 4.0,
+                    product,
+                )
+            }
+            StructuralShape::EllipticalHollow {
+                width,
+                height,
+                thickness,
+                ..
+            } => {
+                let (a, b) = (width / 2.0, height / 2.0);
+                let (c, d) = (a - thickness, b - thickness);
+                (
+                    std::f64::consts::PI * (a * b * b * b - c * d * d * d) / 4.0,
+                    std::f64::consts::PI * (a * a * a * b - c * c * c * d) / 4.0,
+                    product,
+                )
+            }
+            StructuralShape::Rectangle { width, height, .. } => (
+                width * height * height * height / 12.0,
+                height * width * width * width / 12.0,
+                product,
+            ),
+            StructuralShape::Channel {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                let web_height = height - 2.0 * flange_thickness;
+                let (left, _) = self.centroid_offset();
+                (
+                    (width * height * height * height
+                        - (width - web_thickness) * web_height * web_height * web_height)
+                        / 12.0,
+                    (2.0 * flange_thickness * width * width * width
+                        + web_height * web_thickness * web_thickness * web_thickness)
+                        / 3.0
+                        - self.area() * left * left,
+                    product,
+                )
+            }
+            StructuralShape::Angle {
+                width,
+                height,
+                thickness,
+                ..
+            } => {
+                // Moments about the outer faces of the legs, moved to the center of gravity
+                let (left, bottom) = self.centroid_offset();
+                let cube = thickness * thickness * thickness;
+                (
+                    (thickness * height * height * height + (width - thickness) * cube) / 3.0
+                        - self.area() * bottom * bottom,
+                    (thickness * width * width * width + (height - thickness) * cube) / 3.0
+                        - self.area() * left * left,
+                    thickness
+                        * thickness
+                        * (width * width + height * height - thickness * thickness)
+                        / 4.0
+                        - self.area() * left * bottom,
+                )
+            }
+            StructuralShape::Tee {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                // Moment about the bottom of the stem, moved to the center of gravity
+                let (_, bottom) = self.centroid_offset();
+                let stem = height - flange_thickness;
+                (
+                    (width * height * height * height
+                        - (width - web_thickness) * stem * stem * stem)
+                        / 3.0
+                        - self.area() * bottom * bottom,
+                    (flange_thickness * width * width * width
+                        + stem * web_thickness * web_thickness * web_thickness)
+                        / 12.0,
+                    product,
+                )
+            }
+            StructuralShape::DoubleTee {
+                width,
+                height,
+                flange_thickness,
+                stem_width,
+                stem_spacing,
+                ..
+            } => {
+                let (_, bottom) = self.centroid_offset();
+                let stem = height - flange_thickness;
+                (
+                    (width * height * height * height
+                        - (width - 2.0 * stem_width) * stem * stem * stem)
+                        / 3.0
+                        - self.area() * bottom * bottom,
+                    flange_thickness * width * width * width / 12.0
+                        + stem
+                            * stem_width
+                            * (stem_width * stem_width / 6.0 + stem_spacing * stem_spacing / 2.0),
+                    product,
+                )
+            }
+            StructuralShape::PlateGirder {
+                height,
+                web_thickness,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                // Moment about the bottom of the bottom flange, moved to the center of gravity
+                let (_, bottom) = self.centroid_offset();
+                let (below, above) = (bottom_thickness, height - top_thickness);
+                (
+                    (bottom_width * below * below * below
+                        + web_thickness * (above * above * above - below * below * below)
+                        + top_width * (height * height * height - above * above * above))
+                        / 3.0
+                        - self.area() * bottom * bottom,
+                    (bottom_thickness * bottom_width * bottom_width * bottom_width
+                        + (above - below) * web_thickness * web_thickness * web_thickness
+                        + top_thickness * top_width * top_width * top_width)
+                        / 12.0,
+                    product,
+                )
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. } => {
+                let (outline, [cx, cy]) = self.profile_outline();
+                let area = geometry::moment(&outline, 0, 0);
+                (
+                    meters_to_the_fourth(geometry::moment(&outline, 0, 2) - area * cy * cy),
+                    meters_to_the_fourth(geometry::moment(&outline, 2, 0) - area * cx * cx),
+                    meters_to_the_fourth(geometry::moment(&outline, 1, 1) - area * cx * cy),
+                )
+            }
+            StructuralShape::Triangle { .. }
+            | StructuralShape::Trapezoid { .. }
+            | StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. }
+            | StructuralShape::AnnularSector { .. }
+            | StructuralShape::RegularPolygon { .. }
+            | StructuralShape::HollowRegularPolygon { .. } => edge_moments(&self.local_boundary()),
+        }
+    }
+
+    /// This function returns the cross-sectional area of the structural shape
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rod(2.0);
+    /// let area = shape.area();
+    /// ```
+    pub fn area(&self) -> Area {
+        match *self {
+            StructuralShape::Pipe {
+                outer_radius,
+                thickness,
+                ..
+            } => {
+                std::f64::consts::PI
+                    * (outer_radius * outer_radius
+                        - (outer_radius - thickness) * (outer_radius - thickness))
+            }
+            StructuralShape::IBeam { flange_slope, .. } if flange_slope != 0.0 => {
+                Area::new::<square_meter>(geometry::edge_moment(&self.local_boundary(), 0, 0))
+            }
+            StructuralShape::IBeam {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                root_radius,
+                ..
+            } => {
+                width * height - (height - 2.0 * flange_thickness) * (width - web_thickness)
+                    + 4.0 * spandrel(root_radius).0
+            }
+            StructuralShape::Channel {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => width * height - (height - 2.0 * flange_thickness) * (width - web_thickness),
+            StructuralShape::BoxBeam {
+                width,
+                height,
+                flange_thickness,
+                web_thickness,
+                outer_radius,
+                inner_radius,
+                ..
+            } => {
+                width * height
+                    - (width - 2.0 * web_thickness) * (height - 2.0 * flange_thickness)
+                    - 4.0 * (spandrel(outer_radius).0 - spandrel(inner_radius).0)
+            }
+            StructuralShape::Rod { radius, .. } => std::f64::consts::PI * radius * radius,
+            StructuralShape::Ellipse { width, height, .. } => {
+                std::f64::consts::PI * width * height / 4.0
+            }
+            StructuralShape::EllipticalHollow {
+                width,
+                height,
+                thickness,
+                ..
+            } => std::f64::consts::PI * thickness * (width + height - 2.0 * thickness) / 2.0,
+            StructuralShape::Rectangle { width, height, .. } => width * height,
+            StructuralShape::Angle {
+                width,
+                height,
+                thickness,
+                ..
+            } => thickness * (width + height - thickness),
+            StructuralShape::Tee {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => width * flange_thickness + (height - flange_thickness) * web_thickness,
+            StructuralShape::DoubleTee {
+                width,
+                height,
+                flange_thickness,
+                stem_width,
+                ..
+            } => width * flange_thickness + 2.0 * (height - flange_thickness) * stem_width,
+            StructuralShape::PlateGirder {
+                height,
+                web_thickness,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                top_width * top_thickness
+                    + bottom_width * bottom_thickness
+                    + (height - top_thickness - bottom_thickness) * web_thickness
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. } => {
+                let (outline, _) = self.profile_outline();
+                Area::new::<square_meter>(geometry::moment(&outline, 0, 0))
+            }
+            StructuralShape::Triangle { .. }
+            | StructuralShape::Trapezoid { .. }
+            | StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. }
+            | StructuralShape::AnnularSector { .. }
+            | StructuralShape::RegularPolygon { .. }
+            | StructuralShape::HollowRegularPolygon { .. } => {
+                Area::new::<square_meter>(self.outline_integral(0, 0))
+            }
+        }
+    }
+
+    /// This function returns the lower-left and upper-right corners of the bounding box of the structural shape
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(2.0, 1.0).with_cog(1.0, 1.0);
+    /// let (lower_left, upper_right) = shape.bounding_box();
+    /// ```
+    pub fn bounding_box(&self) -> ((Length, Length), (Length, Length)) {
+        let (cx, cy) = self.get_cog();
+        match *self {
+            StructuralShape::Pipe {
+                outer_radius: radius,
+                ..
+            }
+            | StructuralShape::Rod { radius, .. } => {
+                ((cx - radius, cy - radius), (cx + radius, cy + radius))
+            }
+            StructuralShape::Ellipse {
+                width,
+                height,
+                rotation,
+                ..
+            }
+            | StructuralShape::EllipticalHollow {
+                width,
+                height,
+                rotation,
+                ..
+            } => {
+                let (dx, dy) = ellipse_reach(width, height, rotation);
+                ((cx - dx, cy - dy), (cx + dx, cy + dy))
+            }
+            _ => edge_box(&self.local_boundary(), (cx, cy), self.get_rotation()),
+        }
+    }
+
+    /// This function returns the location of the center of gravity measured from the lower-left
+    /// corner of the unrotated shape, such as the distance from the back of the web of a channel
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_channel(0.3, 0.1, 0.01, 0.015);
+    /// let (x_bar, y_bar) = shape.centroid_offset();
+    /// ```
+    pub fn centroid_offset(&self) -> (Length, Length) {
+        let (left, bottom, _, _) = self.local_extents();
+        (left, bottom)
+    }
+
+    /// Corners of the outline of the unrotated shape, counterclockwise and measured from the center
+    /// of gravity. Shapes whose outline fills their bounding rectangle report its four corners.
+    fn local_corners(&self) -> Vec<(Length, Length)> {
+        let (left, bottom, right, top) = self.local_extents();
+        match *self {
+            StructuralShape::Angle { thickness, .. } => vec![
+                (-left, -bottom),
+                (right, -bottom),
+                (right, thickness - bottom),
+                (thickness - left, thickness - bottom),
+                (thickness - left, top),
+                (-left, top),
+            ],
+            StructuralShape::Channel { .. } => self
+                .channel_outline()
+                .iter()
+                .map(|&[x, y]| (meters(x) - left, meters(y)))
+                .collect(),
+            StructuralShape::Tee {
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                let (stem, flange) = (web_thickness / 2.0, top - flange_thickness);
+                vec![
+                    (-stem, -bottom),
+                    (stem, -bottom),
+                    (stem, flange),
+                    (right, flange),
+                    (right, top),
+                    (-left, top),
+                    (-left, flange),
+                    (-stem, flange),
+                ]
+            }
+            StructuralShape::DoubleTee {
+                flange_thickness,
+                stem_width,
+                stem_spacing,
+                ..
+            } => {
+                let (inner, outer) = (
+                    (stem_spacing - stem_width) / 2.0,
+                    (stem_spacing + stem_width) / 2.0,
+                );
+                let flange = top - flange_thickness;
+                vec![
+                    (-outer, -bottom),
+                    (-inner, -bottom),
+                    (-inner, flange),
+                    (inner, flange),
+                    (inner, -bottom),
+                    (outer, -bottom),
+                    (outer, flange),
+                    (right, flange),
+                    (right, top),
+                    (-left, top),
+                    (-left, flange),
+                    (-outer, flange),
+                ]
+            }
+            StructuralShape::PlateGirder {
+                web_thickness,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                let web = web_thickness / 2.0;
+                let (lower, upper) = (bottom_thickness - bottom, top - top_thickness);
+                let (bottom_edge, top_edge) = (bottom_width / 2.0, top_width / 2.0);
+                vec![
+                    (-bottom_edge, -bottom),
+                    (bottom_edge, -bottom),
+                    (bottom_edge, lower),
+                    (web, lower),
+                    (web, upper),
+                    (top_edge, upper),
+                    (top_edge, top),
+                    (-top_edge, top),
+                    (-top_edge, upper),
+                    (-web, upper),
+                    (-web, lower),
+                    (-bottom_edge, lower),
+                ]
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. } => {
+                let (outline, [cx, cy]) = self.profile_outline();
+                outline
+                    .iter()
+                    .map(|&[x, y]| (meters(x - cx), meters(y - cy)))
+                    .collect()
+            }
+            StructuralShape::Triangle { .. } | StructuralShape::Trapezoid { .. } => {
+                self.sloped_corners()
+            }
+            StructuralShape::RegularPolygon { sides, radius, .. }
+            | StructuralShape::HollowRegularPolygon { sides, radius, .. } => {
+                regular_polygon(sides, radius)
+            }
+            _ => vec![
+                (-left, -bottom),
+                (right, -bottom),
+                (right, top),
+                (-left, top),
+            ],
+        }
+    }
+
+    /// Corners of a triangle or trapezoid, counterclockwise and measured from the center of
+    /// gravity. Other shapes have none.
+    fn sloped_corners(&self) -> Vec<(Length, Length)> {
+        match *self {
+            StructuralShape::Triangle {
+                width,
+                height,
+                apex,
+                ..
+            } => centered(&[[0.0, 0.0], [width.value, 0.0], [apex.value, height.value]]),
+            StructuralShape::Trapezoid {
+                bottom_width,
+                top_width,
+                height,
+                top_offset,
+                ..
+            } => centered(&[
+                [0.0, 0.0],
+                [bottom_width.value, 0.0],
+                [(top_offset + top_width).value, height.value],
+                [top_offset.value, height.value],
+            ]),
+            _ => vec![],
+        }
+    }
+
+    /// Corners of a channel, counterclockwise and in meters from the back of its web at half its
+    /// height. Other shapes have none.
+    fn channel_outline(&self) -> Vec<geometry::Point> {
+        match *self {
+            StructuralShape::Channel {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                flange_slope,
+                ..
+            } => {
+                let (b, h, w) = (width.value, height.value / 2.0, web_thickness.value);
+                // The inside faces of the flanges, at the tips and at the web
+                let f = h - flange_thickness.value;
+                let rise = flange_slope * (b - w) / 2.0;
+                let (tip, root) = (f + rise, f - rise);
+                vec![
+                    [0.0, -h],
+                    [b, -h],
+                    [b, -tip],
+                    [w, -root],
+                    [w, root],
+                    [b, tip],
+                    [b, h],
+                    [0.0, h],
+                ]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Rings of the outline of the unrotated shape, measured from the center of gravity. The first
+    /// is the outer boundary, counterclockwise, and any others are holes, clockwise.
+    fn local_outline(&self) -> Vec<Vec<(Length, Length)>> {
+        match *self {
+            StructuralShape::HollowRegularPolygon {
+                sides,
+                radius,
+                thickness,
+                ..
+            } => {
+                // The hole runs clockwise
+                let inner = radius - thickness / (std::f64::consts::PI / sides as f64).cos();
+                let mut hole = regular_polygon(sides, inner);
+                hole.reverse();
+                vec![regular_polygon(sides, radius), hole]
+            }
+            _ => vec![self.local_corners()],
+        }
+    }
+
+    /// Edges around the unrotated shape, in meters from the center of gravity. Shapes with curved
+    /// edges trace them exactly, and the others follow their outline.
+    fn local_boundary(&self) -> Vec<geometry::Edge> {
+        match *self {
+            StructuralShape::IBeam {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                root_radius,
+                flange_slope,
+                ..
+            } => {
+                let (b, h, w) = (
+                    width.value / 2.0,
+                    height.value / 2.0,
+                    web_thickness.value / 2.0,
+                );
+                // The inside faces of the flanges, at the tips and at the web
+                let f = h - flange_thickness.value;
+                let rise = flange_slope * (b - w) / 2.0;
+                let (tip, root) = (f + rise, f - rise);
+                let r = root_radius.value;
+                geometry::rounded(
+                    &[
+                        [-b, -h],
+                        [b, -h],
+                        [b, -tip],
+                        [w, -root],
+                        [w, root],
+                        [b, tip],
+                        [b, h],
+                        [-b, h],
+                        [-b, tip],
+                        [-w, root],
+                        [-w, -root],
+                        [-b, -tip],
+                    ],
+                    &[0.0, 0.0, 0.0, r, r, 0.0, 0.0, 0.0, 0.0, r, r, 0.0],
+                )
+            }
+            StructuralShape::BoxBeam {
+                width,
+                height,
+                flange_thickness,
+                web_thickness,
+                overhang,
+                outer_radius,
+                inner_radius,
+                ..
+            } => {
+                let (b, h, o) = (width.value / 2.0, height.value / 2.0, overhang.value);
+                let (c, d) = (b - o - web_thickness.value, h - flange_thickness.value);
+                let (outer, inner) = (outer_radius.value, inner_radius.value);
+                let mut edges = if o > 0.0 {
+                    // Round the tips of the flanges, and leave the corners under them sharp
+                    let e = b - o;
+                    geometry::rounded(
+                        &[
+                            [-b, -h],
+                            [b, -h],
+                            [b, -d],
+                            [e, -d],
+                            [e, d],
+                            [b, d],
+                            [b, h],
+                            [-b, h],
+                            [-b, d],
+                            [-e, d],
+                            [-e, -d],
+                            [-b, -d],
+                        ],
+                        &[
+                            outer, outer, 0.0, 0.0, 0.0, 0.0, outer, outer, 0.0, 0.0, 0.0, 0.0,
+                        ],
+                    )
+                } else {
+                    geometry::rounded(&[[-b, -h], [b, -h], [b, h], [-b, h]], &[outer; 4])
+                };
+                // The hole runs clockwise
+                edges.extend(geometry::rounded(
+                    &[[-c, -d], [-c, d], [c, d], [c, -d]],
+                    &[inner; 4],
+                ));
+                edges
+            }
+            StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. }
+            | StructuralShape::AnnularSector { .. } => {
+                let [cx, cy] = self.arc_centroid();
+                self.arc_boundary()
+                    .iter()
+                    .map(|edge| edge.place(0.0, [-cx, -cy]))
+                    .collect()
+            }
+            _ => ring_edges(&self.local_outline()),
+        }
+    }
+
+    /// Edges around a shape with curved edges, in meters from the center of its arcs. Other shapes
+    /// have no edges.
+    fn arc_boundary(&self) -> Vec<geometry::Edge> {
+        let (outer, inner, angle) = match *self {
+            StructuralShape::Semicircle { radius, .. } => (radius.value, 0.0, std::f64::consts::PI),
+            StructuralShape::CircularSector { radius, angle, .. } => {
+                (radius.value, 0.0, angle.value)
+            }
+            StructuralShape::AnnularSector {
+                outer_radius,
+                thickness,
+                angle,
+                ..
+            } => (
+                outer_radius.value,
+                (outer_radius - thickness).value,
+                angle.value,
+            ),
+            StructuralShape::CircularSegment { radius, angle, .. } => {
+                let (start, end) = (
+                    std::f64::consts::FRAC_PI_2 - angle.value / 2.0,
+                    std::f64::consts::FRAC_PI_2 + angle.value / 2.0,
+                );
+                let arc = geometry::Edge::Arc([0.0, 0.0], radius.value, start, end);
+                let chord = geometry::Edge::Line(
+                    geometry::place([radius.value, 0.0], end, [0.0, 0.0]),
+                    geometry::place([radius.value, 0.0], start, [0.0, 0.0]),
+                );
+                return vec![arc, chord];
+            }
+            _ => return vec![],
+        };
+        // Straight edges join the ends of the outer arc to the inner arc, or to the apex
+        let (start, end) = (
+            std::f64::consts::FRAC_PI_2 - angle / 2.0,
+            std::f64::consts::FRAC_PI_2 + angle / 2.0,
+        );
+        let point = |radius: f64, angle: f64| geometry::place([radius, 0.0], angle, [0.0, 0.0]);
+        vec![
+            geometry::Edge::Arc([0.0, 0.0], outer, start, end),
+            geometry::Edge::Line(point(outer, end), point(inner, end)),
+            geometry::Edge::Arc([0.0, 0.0], inner, end, start),
+            geometry::Edge::Line(point(inner, start), point(outer, start)),
+        ]
+    }
+
+    /// Center of gravity of a shape with curved edges, in meters from the center of its arcs
+    fn arc_centroid(&self) -> geometry::Point {
+        let boundary = self.arc_boundary();
+        let area = geometry::edge_moment(&boundary, 0, 0);
+        [
+            geometry::edge_moment(&boundary, 1, 0) / area,
+            geometry::edge_moment(&boundary, 0, 1) / area,
+        ]
+    }
+
+    /// Integral of x^p y^q over the unrotated shape, with x and y measured in meters from the
+    /// center of gravity
+    fn outline_integral(&self, p: u32, q: u32) -> f64 {
+        geometry::edge_moment(&self.local_boundary(), p, q)
+    }
+
+    /// Depth of the stems of a double tee, from the centerline of the flange
+    fn double_tee_stem(&self) -> Length {
+        match *self {
+            StructuralShape::DoubleTee {
+                height,
+                flange_thickness,
+                ..
+            } => height - flange_thickness / 2.0,
+            _ => Length::default(),
+        }
+    }
+
+    /// Distance of the shear center of a double tee above the centerline of its flange
+    fn double_tee_eccentricity(&self) -> Length {
+        match *self {
+            StructuralShape::DoubleTee {
+                width,
+                flange_thickness,
+                stem_width,
+                stem_spacing,
+                ..
+            } => {
+                let stem = self.double_tee_stem();
+                let spacing_squared = stem_spacing * stem_spacing;
+                let moi = flange_thickness * width * width * width / 12.0
+                    + stem * stem_width * spacing_squared / 2.0;
+                spacing_squared * stem_width * stem * stem / (4.0 * moi)
+            }
+            _ => Length::default(),
+        }
+    }
+
+    /// Distances from the center of gravity to the left, bottom, right and top edges of the
+    /// unrotated shape
+    fn local_extents(&self) -> (Length, Length, Length, Length) {
+        match *self {
+            StructuralShape::Pipe {
+                outer_radius: radius,
+                ..
+            }
+            | StructuralShape::Rod { radius, .. } => (radius, radius, radius, radius),
+            StructuralShape::Ellipse { width, height, .. }
+            | StructuralShape::EllipticalHollow { width, height, .. } => {
+                (width / 2.0, height / 2.0, width / 2.0, height / 2.0)
+            }
+            StructuralShape::IBeam { width, height, .. }
+            | StructuralShape::BoxBeam { width, height, .. }
+            | StructuralShape::Rectangle { width, height, .. } => {
+                (width / 2.0, height / 2.0, width / 2.0, height / 2.0)
+            }
+            StructuralShape::Channel { width, height, .. } => {
+                let (area, moment, _) = geometry::area_and_moments(&self.channel_outline());
+                let left = meters(moment / area);
+                (left, height / 2.0, width - left, height / 2.0)
+            }
+            StructuralShape::Angle {
+                width,
+                height,
+                thickness,
+                ..
+            } => {
+                let area = self.area();
+                let left = thickness * (height * thickness + width * width - thickness * thickness)
+                    / (2.0 * area);
+                let bottom = thickness
+                    * (width * thickness + height * height - thickness * thickness)
+                    / (2.0 * area);
+                (left, bottom, width - left, height - bottom)
+            }
+            StructuralShape::Tee {
+                width,
+                height,
+                web_thickness,
+                flange_thickness,
+                ..
+            } => {
+                let stem = height - flange_thickness;
+                let bottom = (width * flange_thickness * (height - flange_thickness / 2.0)
+                    + web_thickness * stem * stem / 2.0)
+                    / self.area();
+                (width / 2.0, bottom, width / 2.0, height - bottom)
+            }
+            StructuralShape::DoubleTee {
+                width,
+                height,
+                flange_thickness,
+                stem_width,
+                ..
+            } => {
+                let stem = height - flange_thickness;
+                let bottom = (width * flange_thickness * (height - flange_thickness / 2.0)
+                    + stem_width * stem * stem)
+                    / self.area();
+                (width / 2.0, bottom, width / 2.0, height - bottom)
+            }
+            StructuralShape::PlateGirder {
+                height,
+                web_thickness,
+                top_width,
+                top_thickness,
+                bottom_width,
+                bottom_thickness,
+                ..
+            } => {
+                let web = height - top_thickness - bottom_thickness;
+                let bottom = (bottom_width * bottom_thickness * bottom_thickness / 2.0
+                    + web * web_thickness * (bottom_thickness + web / 2.0)
+                    + top_width * top_thickness * (height - top_thickness / 2.0))
+                    / self.area();
+                let half = top_width.max(bottom_width) / 2.0;
+                (half, bottom, half, height - bottom)
+            }
+            StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. } => {
+                let (outline, [cx, cy]) = self.profile_outline();
+                let (mut left, mut bottom, mut right, mut top) =
+                    (0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+                for &[x, y] in &outline {
+                    left = left.max(cx - x);
+                    bottom = bottom.max(cy - y);
+                    right = right.max(x - cx);
+                    top = top.max(y - cy);
+                }
+                (meters(left), meters(bottom), meters(right), meters(top))
+            }
+            StructuralShape::Triangle { .. } | StructuralShape::Trapezoid { .. } => {
+                extents(&self.sloped_corners())
+            }
+            StructuralShape::RegularPolygon { sides, radius, .. }
+            | StructuralShape::HollowRegularPolygon { sides, radius, .. } => {
+                extents(&regular_polygon(sides, radius))
+            }
+            StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. }
+            | StructuralShape::AnnularSector { .. } => {
+                let (lower, upper) = geometry::edge_bounds(&self.local_boundary());
+                (
+                    meters(-lower[0]),
+                    meters(-lower[1]),
+                    meters(upper[0]),
+                    meters(upper[1]),
+                )
+            }
+        }
+    }
+
+    /// Centerline of the wall of a cold-formed shape with its bends rounded, and the thickness of
+    /// the wall, in meters. The centerline is measured from mid-height on the web, or on the axis
+    /// of symmetry of a hat. Other shapes have an empty centerline.
+    fn profile(&self) -> (Vec<geometry::Point>, f64) {
+        let (corners, thickness, bend_radius) = match *self {
+            StructuralShape::LippedChannel {
+                width,
+                height,
+                lip,
+                thickness,
+                bend_radius,
+                ..
+            } => {
+                let (b, h, c) = (width.value, height.value / 2.0, lip.value);
+                (
+                    vec![[b, h - c], [b, h], [0.0, h], [0.0, -h], [b, -h], [b, c - h]],
+                    thickness,
+                    bend_radius,
+                )
+            }
+            StructuralShape::LippedZ {
+                width,
+                height,
+                lip,
+                thickness,
+                bend_radius,
+                ..
+            } => {
+                let (b, h, c) = (width.value, height.value / 2.0, lip.value);
+                (
+                    vec![
+                        [b, h - c],
+                        [b, h],
+                        [0.0, h],
+                        [0.0, -h],
+                        [-b, -h],
+                        [-b, c - h],
+                    ],
+                    thickness,
+                    bend_radius,
+                )
+            }
+            StructuralShape::Sigma {
+                width,
+                height,
+                lip,
+                web_flat,
+                indent,
+                indent_height,
+                thickness,
+                bend_radius,
+                ..
+            } => {
+                let (b, h, c) = (width.value, height.value / 2.0, lip.value);
+                let (a, d, e) = (web_flat.value, indent.value, indent_height.value / 2.0);
+                (
+                    vec![
+                        [b, h - c],
+                        [b, h],
+                        [0.0, h],
+                        [0.0, h - a],
+                        [d, e],
+                        [d, -e],
+                        [0.0, a - h],
+                        [0.0, -h],
+                        [b, -h],
+                        [b, c - h],
+                    ],
+                    thickness,
+                    bend_radius,
+                )
+            }
+            StructuralShape::Hat {
+                width,
+                height,
+                flange,
+                thickness,
+                bend_radius,
+                ..
+            } => {
+                let (b, h, f) = (width.value / 2.0, height.value / 2.0, flange.value);
+                (
+                    vec![
+                        [-b - f, -h],
+                        [-b, -h],
+                        [-b, h],
+                        [b, h],
+                        [b, -h],
+                        [b + f, -h],
+                    ],
+                    thickness,
+                    bend_radius,
+                )
+            }
+            _ => return (vec![], 0.0),
+        };
+        // Lips of zero length leave repeated points, which have no direction to bend through
+        let mut corners = corners;
+        corners.dedup();
+        let radius = bend_radius.value + thickness.value / 2.0;
+        (
+            geometry::fillet(&corners, radius, BEND_SEGMENTS),
+            thickness.value,
+        )
+    }
+
+    /// Torsion and warping properties along the centerline of a box beam, annular sector or
+    /// cold-formed shape, the last in the frame of its profile
+    fn wall_analysis(&self) -> thin_walled::Analysis {
+        match *self {
+            StructuralShape::BoxBeam {
+                width,
+                height,
+                flange_thickness,
+                web_thickness,
+                overhang,
+                ..
+            } => {
+                // A single cell along the midline of the walls, with the overhangs as outstands
+                let (tf, tw) = (flange_thickness.value, web_thickness.value);
+                let b = (width - 2.0 * overhang - web_thickness).value / 2.0;
+                let h = (height - flange_thickness).value / 2.0;
+                let mut nodes = vec![[-b, -h], [b, -h], [b, h], [-b, h]];
+                let mut walls = vec![(0, 1, tf), (1, 2, tw), (2, 3, tf), (3, 0, tw)];
+                if overhang.value > 0.0 {
+                    let tip = b + overhang.value;
+                    nodes.extend(&[[-tip, -h], [tip, -h], [tip, h], [-tip, h]]);
+                    walls.extend(&[(0, 4, tf), (1, 5, tf), (2, 6, tf), (3, 7, tf)]);
+                }
+                thin_walled::analyse(&nodes, &walls)
+            }
+            StructuralShape::AnnularSector {
+                outer_radius,
+                thickness,
+                angle,
+                ..
+            } => {
+                // Along the middle of the wall, in straight pieces as fine as the bends of the
+                // cold-formed shapes
+                let radius = (outer_radius - thickness / 2.0).value;
+                let pieces = ((angle.value / std::f64::consts::FRAC_PI_2 * BEND_SEGMENTS as f64)
+                    .ceil() as usize)
+                    .max(1);
+                let [cx, cy] = self.arc_centroid();
+                let centerline: Vec<geometry::Point> = (0..=pieces)
+                    .map(|i| {
+                        let turn = angle.value * (i as f64 / pieces as f64 - 0.5);
+                        geometry::place([0.0, radius], turn, [-cx, -cy])
+                    })
+                    .collect();
+                let walls: Vec<thin_walled::Wall> = (1..centerline.len())
+                    .map(|i| (i - 1, i, thickness.value))
+                    .collect();
+                thin_walled::analyse(&centerline, &walls)
+            }
+            _ => {
+                let (centerline, thickness) = self.profile();
+                let walls: Vec<thin_walled::Wall> = (1..centerline.len())
+                    .map(|i| (i - 1, i, thickness))
+                    .collect();
+                thin_walled::analyse(&centerline, &walls)
+            }
+        }
+    }
+
+    /// Outline of a cold-formed shape and its center of gravity, in the frame of its centerline
+    fn profile_outline(&self) -> (Vec<geometry::Point>, geometry::Point) {
+        let (centerline, thickness) = self.profile();
+        let outline = geometry::thicken(&centerline, thickness);
+        let (area, moment_x, moment_y) = geometry::area_and_moments(&outline);
+        (outline, [moment_x / area, moment_y / area])
+    }
+
+    /// This function returns the elastic section moduli for bending about the centroidal x-axis,
+    /// for the top and bottom fibres respectively
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let (top, bottom) = shape.section_modulus_x();
+    /// ```
+    pub fn section_modulus_x(&self) -> (Volume, Volume) {
+        let (ix, _, _) = self.centroidal_moments();
+        let ((_, y_min), (_, y_max)) = self.bounding_box();
+        let (_, cy) = self.get_cog();
+        (ix / (y_max - cy), ix / (cy - y_min))
+    }
+
+    /// This function returns the elastic section moduli for bending about the centroidal y-axis,
+    /// for the left and right fibres respectively
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let (left, right) = shape.section_modulus_y();
+    /// ```
+    pub fn section_modulus_y(&self) -> (Volume, Volume) {
+        let (_, iy, _) = self.centroidal_moments();
+        let ((x_min, _), (x_max, _)) = self.bounding_box();
+        let (cx, _) = self.get_cog();
+        (iy / (cx - x_min), iy / (x_max - cx))
+    }
+
+    /// This function returns the location of the plastic neutral axes, which divide the area of
+    /// the structural shape in half. The first element is the x-coordinate of the axis for bending
+    /// about the y-axis and the second is the y-coordinate of the axis for bending about the x-axis.
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let (x, y) = shape.plastic_neutral_axis();
+    /// ```
+    pub fn plastic_neutral_axis(&self) -> (Length, Length) {
+        CompositeShape::new().add(*self).plastic_neutral_axis()
+    }
+
+    /// This function returns the plastic section modulus for bending about the x-axis
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let zx = shape.plastic_modulus_x();
+    /// ```
+    pub fn plastic_modulus_x(&self) -> Volume {
+        CompositeShape::new().add(*self).plastic_modulus_x()
+    }
+
+    /// This function returns the plastic section modulus for bending about the y-axis
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+    /// let zy = shape.plastic_modulus_y();
+    /// ```
+    pub fn plastic_modulus_y(&self) -> Volume {
+        CompositeShape::new().add(*self).plastic_modulus_y()
+    }
+
+    /// This function returns the shape factor for bending about the x-axis, the ratio of the plastic
+    /// section modulus to the smaller elastic section modulus
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(2.0, 1.0);
+    /// let factor = shape.shape_factor_x();
+    /// ```
+    pub fn shape_factor_x(&self) -> f64 {
+        CompositeShape::new().add(*self).shape_factor_x()
+    }
+
+    /// This function returns the shape factor for bending about the y-axis, the ratio of the plastic
+    /// section modulus to the smaller elastic section modulus
+    /// ```
+    /// # use structural_shapes::StructuralShape;
+    /// let shape = StructuralShape::new_rectangle(2.0, 1.0);
+    /// let factor = shape.shape_factor_y();
+    /// ```
+    pub fn shape_factor_y(&self) -> f64 {
+        CompositeShape::new().add(*self).shape_factor_y()
+    }
+
+    /// Area and first moment of area of the part of the shape beyond an axis-aligned line. For
+    /// `Axis::X` this is the part above `y = offset` and the first moment is about the x-axis; for
+    /// `Axis::Y` this is the part to the right of `x = offset` and the moment is about the y-axis.
+    fn split(&self, axis: Axis, offset: Length) -> (Area, Volume) {
+        match *self {
+            StructuralShape::Pipe {
+                outer_radius,
+                thickness,
+                center_of_gravity,
+                ..
+            } => composite_pipe(outer_radius, thickness, center_of_gravity).split(axis, offset),
+            StructuralShape::Rod {
+                radius,
+                center_of_gravity,
+                ..
+            } => {
+                let center = axis.select(center_of_gravity);
+                let distance = ((offset - center) / radius).value;
+                if distance >= 1.0 {
+                    (Area::default(), Volume::default())
+                } else if distance <= -1.0 {
+                    (self.area(), self.area() * center)
+                } else {
+                    let chord = (1.0 - distance * distance).sqrt();
+                    let area = radius * radius * (distance.acos() - distance * chord);
+                    let moment = area * center
+                        + 2.0 / 3.0 * radius * radius * radius * chord * chord * chord;
+                    (area, moment)
+                }
+            }
+            StructuralShape::Ellipse {
+                width,
+                height,
+                center_of_gravity,
+                rotation,
+            } => {
+                // The ellipse is a stretched circle, whose radius across the axis is its reach
+                let (a, b) = (width / 2.0, height / 2.0);
+                let reach = axis.select(ellipse_reach(width, height, rotation));
+                let center = axis.select(center_of_gravity);
+                let distance = ((offset - center) / reach).value;
+                if distance >= 1.0 {
+                    (Area::default(), Volume::default())
+                } else if distance <= -1.0 {
+                    (self.area(), self.area() * center)
+                } else {
+                    let chord = (1.0 - distance * distance).sqrt();
+                    let area = a * b * (distance.acos() - distance * chord);
+                    let moment = area * center + 2.0 / 3.0 * a * b * reach * chord * chord * chord;
+                    (area, moment)
+                }
+            }
+            StructuralShape::EllipticalHollow {
+                width,
+                height,
+                thickness,
+                center_of_gravity,
+                rotation,
+            } => composite_elliptical_hollow(width, height, thickness, center_of_gravity, rotation)
+                .split(axis, offset),
+            StructuralShape::Rectangle { .. }
+            | StructuralShape::IBeam { .. }
+            | StructuralShape::BoxBeam { .. }
+            | StructuralShape::Channel { .. }
+            | StructuralShape::Angle { .. }
+            | StructuralShape::Tee { .. }
+            | StructuralShape::DoubleTee { .. }
+            | StructuralShape::PlateGirder { .. }
+            | StructuralShape::LippedChannel { .. }
+            | StructuralShape::LippedZ { .. }
+            | StructuralShape::Sigma { .. }
+            | StructuralShape::Hat { .. }
+            | StructuralShape::Triangle { .. }
+            | StructuralShape::Trapezoid { .. }
+            | StructuralShape::Semicircle { .. }
+            | StructuralShape::CircularSegment { .. }
+            | StructuralShape::CircularSector { .. }
+            | StructuralShape::AnnularSector { .. }
+            | StructuralShape::RegularPolygon { .. }
+            | StructuralShape::HollowRegularPolygon { .. } => split_edges(
+                &self.local_boundary(),
+                self.get_cog(),
+                self.get_rotation(),
+                axis,
+                offset,
+            ),
+        }
+    }
+
+    /// A function to set the rotation of a shape about its center of gravity, in radians
+    /// counterclockwise
+    /// ```
+    /// # use structural_shapes::{StructuralShape};
+    /// let shape = StructuralShape::new_rectangle(2.0, 0.1).with_rotation(std::f64::consts::FRAC_PI_4);
+    /// let moi = shape.moi_x();
+    /// ```
+    pub fn with_rotation(&mut self, angle: f64) -> StructuralShape {
+        self.set_rotation(radians(angle));
+        *self
+    }
+
+    /// A function to return the current rotation of a shape
+    pub(crate) fn get_rotation(&self) -> Angle {
+        match *self {
+            StructuralShape::Pipe { rotation, .. }
+            | StructuralShape::IBeam { rotation, .. }
+            | StructuralShape::BoxBeam { rotation, .. }
+            | StructuralShape::Rod { rotation, .. }
+            | StructuralShape::Rectangle { rotation, .. }
+            | StructuralShape::Channel { rotation, .. }
+            | StructuralShape::Angle { rotation, .. }
+            | StructuralShape::Tee { rotation, .. }
+            | StructuralShape::DoubleTee { rotation, .. }
+            | StructuralShape::LippedChannel { rotation, .. }
+            | StructuralShape::LippedZ { rotation, .. }
+            | StructuralShape::Sigma { rotation, .. }
+            | StructuralShape::Hat { rotation, .. }
+            | StructuralShape::Ellipse { rotation, .. }
+            | StructuralShape::EllipticalHollow { rotation, .. }
+            | StructuralShape::Triangle { rotation, .. }
+            | StructuralShape::Trapezoid { rotation, .. }
+            | StructuralShape::Semicircle { rotation, .. }
+            | StructuralShape::CircularSegment { rotation, .. }
+            | StructuralShape::CircularSector { rotation, .. }
+            | StructuralShape::AnnularSector { rotation, .. }
+            | StructuralShape::PlateGirder { rotation, .. }
+            | StructuralShape::RegularPolygon { rotation, .. }
+            | StructuralShape::HollowRegularPolygon { rotation, .. } => rotation,
+        }
+    }
+
+    /// A function to set the current rotation of a shape
+    pub(crate) fn set_rotation(&mut self, angle: Angle) {
+        match *self {
+            StructuralShape::Pipe {
+                ref mut rotation, ..
+            }
+            | StructuralShape::IBeam {
+                ref mut rotation, ..
+            }
+            | StructuralShape::BoxBeam {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Rod {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Rectangle {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Channel {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Angle {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Tee {
+                ref mut rotation, ..
+            }
+            | StructuralShape::DoubleTee {
+                ref mut rotation, ..
+            }
+            | StructuralShape::LippedChannel {
+                ref mut rotation, ..
+            }
+            | StructuralShape::LippedZ {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Sigma {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Hat {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Ellipse {
+                ref mut rotation, ..
+            }
+            | StructuralShape::EllipticalHollow {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Triangle {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Trapezoid {
+                ref mut rotation, ..
+            }
+            | StructuralShape::Semicircle {
+                ref mut rotation, ..
+            }
+            | StructuralShape::CircularSegment {
+                ref mut rotation, ..
+            }
+            | StructuralShape::CircularSector {
+                ref mut rotation, ..
+            }
+            | StructuralShape::AnnularSector {
+                ref mut rotation, ..
+            }
+            | StructuralShape::PlateGirder {
+                ref mut rotation, ..
+            }
+            | StructuralShape::RegularPolygon {
+                ref mut rotation, ..
+            }
+            | StructuralShape::HollowRegularPolygon {
+                ref mut rotation, ..
+            } => {
+                *rotation = angle;
+            }
+        };
+    }
+
+    /// A function to set the center of gravity of a shape
+    /// ```
+    /// # use structural_shapes::{StructuralShape};
+    /// let shape = StructuralShape::new_rod(2.0).with_cog(0.0, 0.0);
+    /// let moi = shape.moi_x();
+    /// ```
+    pub fn with_cog(&mut self, x: f64, y: f64) -> StructuralShape {
+        self.set_cog((meters(x), meters(y)));
+        *self
+    }
+
+    /// A function to return the current center of gravity for a shape
+    pub(crate) fn get_cog(&self) -> (Length, Length) {
+        match *self {
+            StructuralShape::Pipe {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::IBeam {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::BoxBeam {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Rod {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Rectangle {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Channel {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Angle {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Tee {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::DoubleTee {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::LippedChannel {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::LippedZ {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Sigma {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Hat {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Ellipse {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::EllipticalHollow {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Triangle {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Trapezoid {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::Semicircle {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::CircularSegment {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::CircularSector {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::AnnularSector {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::PlateGirder {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::RegularPolygon {
+                center_of_gravity, ..
+            } => center_of_gravity,
+            StructuralShape::HollowRegularPolygon {
+                center_of_gravity, ..
+            } => center_of_gravity,
+        }
+    }
+
+    /// A function to set the current center of gravity for a shape
+    pub(crate) fn set_cog(&mut self, cog: (Length, Length)) {
+        match *self {
+            StructuralShape::Pipe {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::IBeam {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::BoxBeam {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Rod {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Rectangle {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Channel {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Angle {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Tee {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::DoubleTee {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::LippedChannel {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::LippedZ {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Sigma {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Hat {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Ellipse {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::EllipticalHollow {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Triangle {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Trapezoid {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::Semicircle {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::CircularSegment {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::CircularSector {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::AnnularSector {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::PlateGirder {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::RegularPolygon {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+            StructuralShape::HollowRegularPolygon {
+                ref mut center_of_gravity,
+                ..
+            } => {
+                *center_of_gravity = cog;
+            }
+        };
+    }
+}
+
+/// Implement the properties that polygons and thin-walled sections both find from the edges
+/// around their unrotated outline, along with `Member`. The type must have `center_of_gravity` and
+/// `rotation` fields and provide `boundary`, `local_extents` and `composite`.
+macro_rules! outline_properties {
+    ($shape:ident, $noun:literal) => {
+        impl $shape {
+            #[doc = concat!("This function returns the cross-sectional area of the ", $noun)]
+            pub fn area(&self) -> Area {
+                Area::new::<square_meter>(geometry::edge_moment(&self.boundary(), 0, 0))
+            }
+
+            #[doc = concat!("This function returns the moment of inertia of the ", $noun, " around the x-axis")]
+            pub fn moi_x(&self) -> SecondAreaMomentofInertia {
+                let (_, cy) = self.center_of_gravity;
+                self.centroidal_moi_x() + self.area() * cy * cy
+            }
+
+            #[doc = concat!("This function returns the moment of inertia of the ", $noun, " around the y-axis")]
+            pub fn moi_y(&self) -> SecondAreaMomentofInertia {
+                let (cx, _) = self.center_of_gravity;
+                self.centroidal_moi_y() + self.area() * cx * cx
+            }
+
+            #[doc = concat!("This function returns the product moment of inertia of the ", $noun, " about the x- and y-axes")]
+            pub fn product_moi(&self) -> SecondAreaMomentofInertia {
+                let (cx, cy) = self.center_of_gravity;
+                self.centroidal_product_moi() + self.area() * cx * cy
+            }
+
+            #[doc = concat!("This function returns the polar moment of inertia of the ", $noun, " about the origin")]
+            pub fn polar_moi(&self) -> SecondAreaMomentofInertia {
+                self.moi_x() + self.moi_y()
+            }
+
+            #[doc = concat!("This function returns the moment of inertia of the ", $noun, " about the horizontal axis through its center of gravity")]
+            pub fn centroidal_moi_x(&self) -> SecondAreaMomentofInertia {
+                self.centroidal_moments().0
+            }
+
+            #[doc = concat!("This function returns the moment of inertia of the ", $noun, " about the vertical axis through its center of gravity")]
+            pub fn centroidal_moi_y(&self) -> SecondAreaMomentofInertia {
+                self.centroidal_moments().1
+            }
+
+            #[doc = concat!("This function returns the product moment of inertia of the ", $noun, " about the axes through its center of gravity")]
+            pub fn centroidal_product_moi(&self) -> SecondAreaMomentofInertia {
+                self.centroidal_moments().2
+            }
+
+            #[doc = concat!("This function returns the moment of inertia of the ", $noun, " about an axis through `point`, inclined at `angle` counterclockwise from the x-axis")]
+            pub fn moi_about(
+                &self,
+                point: (Length, Length),
+                angle: Angle,
+            ) -> SecondAreaMomentofInertia {
+                let moments = self.centroidal_moments();
+                moi_about(self.area(), self.center_of_gravity, moments, point, angle)
+            }
+
+            /// This function returns the angle from the x-axis to the major principal axis,
+            /// measured counterclockwise about the center of gravity
+            pub fn principal_angle(&self) -> Angle {
+                let (ix, iy, ixy) = self.centroidal_moments();
+                principal_axes(ix, iy, ixy).0
+            }
+
+            /// This function returns the major and minor principal moments of inertia about the
+            /// center of gravity
+            pub fn principal_moi(&self) -> (SecondAreaMomentofInertia, SecondAreaMomentofInertia) {
+                let (ix, iy, ixy) = self.centroidal_moments();
+                let (_, i1, i2) = principal_axes(ix, iy, ixy);
+                (i1, i2)
+            }
+
+            /// This function returns the radius of gyration about the centroidal x-axis
+            pub fn radius_of_gyration_x(&self) -> Length {
+                (self.centroidal_moi_x() / self.area()).sqrt()
+            }
+
+            /// This function returns the radius of gyration about the centroidal y-axis
+            pub fn radius_of_gyration_y(&self) -> Length {
+                (self.centroidal_moi_y() / self.area()).sqrt()
+            }
+
+            /// This function returns the least radius of gyration, about the minor principal axis
+            pub fn radius_of_gyration_min(&self) -> Length {
+                (self.principal_moi().1 / self.area()).sqrt()
+            }
+
+            /// This function returns the slenderness ratio KL/r for an effective length KL, using
+            /// the least radius of gyration
+            pub fn slenderness(&self, effective_length: Length) -> f64 {
+                (effective_length / self.radius_of_gyration_min()).value
+            }
+
+            #[doc = concat!("This function returns the lower-left and upper-right corners of the bounding box of the ", $noun)]
+            pub fn bounding_box(&self) -> ((Length, Length), (Length, Length)) {
+                edge_box(&self.boundary(), self.center_of_gravity, self.rotation)
+            }
+
+            #[doc = concat!("This function returns the location of the center of gravity measured from the lower-left corner of the bounding box of the unrotated ", $noun)]
+            pub fn centroid_offset(&self) -> (Length, Length) {
+                let (left, bottom, _, _) = self.local_extents();
+                (left, bottom)
+            }
+
+            /// This function returns the elastic section moduli for bending about the centroidal
+            /// x-axis, for the top and bottom fibres respectively
+            pub fn section_modulus_x(&self) -> (Volume, Volume) {
+                self.composite().section_modulus_x()
+            }
+
+            /// This function returns the elastic section moduli for bending about the centroidal
+            /// y-axis, for the left and right fibres respectively
+            pub fn section_modulus_y(&self) -> (Volume, Volume) {
+                self.composite().section_modulus_y()
+            }
+
+            /// This function returns the location of the plastic neutral axes, as for
+            /// `StructuralShape::plastic_neutral_axis`
+            pub fn plastic_neutral_axis(&self) -> (Length, Length) {
+                self.composite().plastic_neutral_axis()
+            }
+
+            /// This function returns the plastic section modulus for bending about the x-axis
+            pub fn plastic_modulus_x(&self) -> Volume {
+                self.composite().plastic_modulus_x()
+            }
+
+            /// This function returns the plastic section modulus for bending about the y-axis
+            pub fn plastic_modulus_y(&self) -> Volume {
+                self.composite().plastic_modulus_y()
+            }
+
+            /// This function returns the shape factor for bending about the x-axis
+            pub fn shape_factor_x(&self) -> f64 {
+                self.composite().shape_factor_x()
+            }
+
+            /// This function returns the shape factor for bending about the y-axis
+            pub fn shape_factor_y(&self) -> f64 {
+                self.composite().shape_factor_y()
+            }
+
+            /// Moments and product of inertia about axes through the center of gravity
+            fn centroidal_moments(
+                &self,
+            ) -> (
+                SecondAreaMomentofInertia,
+                SecondAreaMomentofInertia,
+                SecondAreaMomentofInertia,
+            ) {
+                rotate_moments(edge_moments(&self.boundary()), self.rotation)
+            }
+        }
+
+        impl Member for $shape {
+            fn area(&self) -> Area {
+                $shape::area(self)
+            }
+            fn moi_x(&self) -> SecondAreaMomentofInertia {
+                $shape::moi_x(self)
+            }
+            fn moi_y(&self) -> SecondAreaMomentofInertia {
+                $shape::moi_y(self)
+            }
+            fn product_moi(&self) -> SecondAreaMomentofInertia {
+                $shape::product_moi(self)
+            }
+            fn get_cog(&self) -> (Length, Length) {
+                self.center_of_gravity
+            }
+            fn set_cog(&mut self, cog: (Length, Length)) {
+                self.center_of_gravity = cog;
+            }
+            fn bounding_box(&self) -> ((Length, Length), (Length, Length)) {
+                $shape::bounding_box(self)
+            }
+            fn split(&self, axis: Axis, offset: Length) -> (Area, Volume) {
+                split_edges(
+                    &self.boundary(),
+                    self.center_of_gravity,
+                    self.rotation,
+                    axis,
+                    offset,
+                )
+            }
+        }
+    };
+}
+
+/// A polygon with an outer boundary and any number of holes. Its vertices are measured from the
+/// center of gravity of the unrotated polygon, and it follows the same axis convention as
+/// `StructuralShape`. Add it to a composite with `CompositeShape::add_polygon`.
+/// ```
+/// # use structural_shapes::{CompositeShape, PolygonShape, StructuralShape};
+/// let plate = PolygonShape::new(&[(0.0, 0.0), (0.3, 0.0), (0.0, 0.2)], &[]);
+/// let x = CompositeShape::new()
+///     .add(StructuralShape::new_rectangle(0.01, 0.3).with_cog(0.15, -0.005))
+///     .add_polygon(plate);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolygonShape {
+    /// Vertices of the outer boundary, counterclockwise
+    pub outer: Vec<(Length, Length)>,
+    /// Vertices of each hole, clockwise
+    pub holes: Vec<Vec<(Length, Length)>>,
+    /// Coordinates of center of gravity
+    pub center_of_gravity: (Length, Length),
+    /// Counterclockwise rotation about the center of gravity
+    pub rotation: Angle,
+}
+
+impl PolygonShape {
+    /// Make a new polygon from the coordinates of the vertices of its outer boundary and of each of
+    /// its holes, which may be listed in either direction. Unlike the other shapes, the center of
+    /// gravity is placed at the centroid of the polygon in the coordinates given. This panics if a
+    /// ring has fewer than three vertices or if the polygon encloses no area, as when its outer
+    /// boundary is a straight line or crosses itself so that its parts cancel out.
+    /// ```
+    /// # use structural_shapes::PolygonShape;
+    /// let outer = [(0.0, 0.0), (0.2, 0.0), (0.2, 0.1), (0.0, 0.1)];
+    /// let hole = vec![(0.05, 0.025), (0.15, 0.025), (0.15, 0.075), (0.05, 0.075)];
+    /// let shape = PolygonShape::new(&outer, &[hole]);
+    /// ```
+    pub fn new(outer: &[(f64, f64)], holes: &[Vec<(f64, f64)>]) -> PolygonShape {
+        assert!(
+            outer.len() >= 3 && holes.iter().all(|hole| hole.len() >= 3),
+            "The outer boundary and every hole must have at least three vertices."
+        );
+        // Orient the boundary counterclockwise and the holes clockwise, so that the integrals over
+        // every ring add up to those over the polygon
+        let orient = |ring: &[(f64, f64)], counterclockwise: bool| -> Vec<geometry::Point> {
+            let mut ring: Vec<geometry::Point> = ring.iter().map(|&(x, y)| [x, y]).collect();
+            if (geometry::moment(&ring, 0, 0) > 0.0) != counterclockwise {
+                ring.reverse();
+            }
+            ring
+        };
+        let mut rings = vec![orient(outer, true)];
+        rings.extend(holes.iter().map(|hole| orient(hole, false)));
+        let (area, moment_x, moment_y) = rings
+            .iter()
+            .map(|ring| geometry::area_and_moments(ring))
+            .fold((0.0, 0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2));
+        // Rounding can leave a trace of area in a boundary that encloses none, so compare it with
+        // the size of the boundary
+        let (lower, upper) = geometry::edge_bounds(&geometry::edges(&rings[..1]));
+        let size = (upper[0] - lower[0]).max(upper[1] - lower[1]);
+        assert!(
+            area > f64::EPSILON * size * size,
+            "The polygon must enclose a nonzero area."
+        );
+        let (cx, cy) = (moment_x / area, moment_y / area);
+        let mut rings = rings.iter().map(|ring| {
+            ring.iter()
+                .map(|&[x, y]| (meters(x - cx), meters(y - cy)))
+                .collect()
+        });
+        PolygonShape {
+            outer: rings.next().unwrap_or_default(),
+            holes: rings.collect(),
+            center_of_gravity: (meters(cx), meters(cy)),
+            rotation: radians(0.0),
+        }
+    }
+
+    /// A function to set the center of gravity of a polygon
+    /// ```
+    /// # use structural_shapes::PolygonShape;
+    /// let shape = PolygonShape::new(&[(0.0, 0.0), (0.3, 0.0), (0.0, 0.3)], &[]).with_cog(1.0, 0.0);
+    /// ```
+    pub fn with_cog(&mut self, x: f64, y: f64) -> PolygonShape {
+        self.center_of_gravity = (meters(x), meters(y));
+        self.clone()
+    }
+
+    /// A function to set the rotation of a polygon about its center of gravity, in radians
+    /// counterclockwise
+    /// ```
+    /// # use structural_shapes::PolygonShape;
+    /// let shape = PolygonShape::new(&[(0.0, 0.0), (0.3, 0.0), (0.0, 0.3)], &[]).with_rotation(1.0);
+    /// ```
+    pub fn with_rotation(&mut self, angle: f64) -> PolygonShape {
+        self.rotation = radians(angle);
+        self.clone()
+    }
+
+    /// Edges around the unrotated polygon, in meters from the center of gravity
+    fn boundary(&self) -> Vec<geometry::Edge> {
+        let mut rings = vec![self.outer.clone()];
+        rings.extend(self.holes.iter().cloned());
+        ring_edges(&rings)
+    }
+
+    /// Distances from the center of gravity to the left, bottom, right and top edges of the
+    /// unrotated polygon
+    fn local_extents(&self) -> (Length, Length, Length, Length) {
+        extents(&self.outer)
+    }
+
+    /// A composite holding only this polygon
+    fn composite(&self) -> CompositeShape {
+        CompositeShape::new().add_polygon(self.clone())
+    }
+}
+
+outline_properties!(PolygonShape, "polygon");
+
+/// A thin-walled section made of straight walls between nodes, which may form any number of closed
+/// cells. Each wall is a rectangle centered on its centerline, so overlaps and gaps at the joints
+/// are ignored, while torsion and warping are found along the centerlines. The nodes are measured
+/// from the center of gravity of the unrotated section, and it follows the same axis convention as
+/// `StructuralShape`. Add it to a composite with `CompositeShape::add_thin_walled`.
+/// ```
+/// # use structural_shapes::{CompositeShape, StructuralShape, ThinWalledShape};
+/// let points = [(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+/// let channel = ThinWalledShape::new_polyline(&points, &[0.01, 0.008, 0.01]);
+/// let x = CompositeShape::new()
+///     .add_thin_walled(channel)
+///     .add(StructuralShape::new_rectangle(0.3, 0.02).with_cog(-0.014, 0.15));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThinWalledShape {
+    /// Coordinates of the nodes
+    pub nodes: Vec<(Length, Length)>,
+    /// The two nodes joined by each wall, and its thickness
+    pub walls: Vec<(usize, usize, Length)>,
+    /// Coordinates of center of gravity
+    pub center_of_gravity: (Length, Length),
+    /// Counterclockwise rotation about the center of gravity
+    pub rotation: Angle,
+}
+
+impl ThinWalledShape {
+    /// Make a new thin-walled section from the coordinates of its nodes and the walls between
+    /// them, each given by the indices of its two nodes and its thickness. Walls should only meet
+    /// at nodes. Like a polygon, the center of gravity is placed at the centroid of the section in
+    /// the coordinates given. This panics if there are no walls, or if a wall refers to a node that
+    /// does not exist, joins a node to itself or to another at the same place, or is not thicker
+    /// than zero.
     /// ```
-    /// # use structural_shapes::StructuralShape;
-    /// let shape = StructuralShape::new_rod(2.0);
-    /// let area = shape.area();
+    /// # use structural_shapes::ThinWalledShape;
+    /// // A box with a middle web, making two cells
+    /// let nodes = [(0.0, 0.0), (0.2, 0.0), (0.4, 0.0), (0.4, 0.3), (0.2, 0.3), (0.0, 0.3)];
+    /// let walls = [(0, 1, 0.01), (1, 2, 0.01), (2, 3, 0.008), (3, 4, 0.01), (4, 5, 0.01),
+    ///     (5, 0, 0.008), (1, 4, 0.006)];
+    /// let shape = ThinWalledShape::new(&nodes, &walls);
     /// ```
-    pub fn area(&self) -> Area {
-        match *self {
-            StructuralShape::Pipe {
-                outer_radius,
-                thickness,
-                ..
-            } => {
-                std::f64::consts::PI
-                    * (outer_radius * outer_radius
-                        - (outer_radius - thickness) * (outer_radius - thickness))
-            }
-            StructuralShape::IBeam {
-                width,
-                height,
-                web_thickness,
-                flange_thickness,
-                ..
-            } => width * height - (height - 2.0 * flange_thickness) * (width - web_thickness),
-            StructuralShape::BoxBeam {
-                width,
-                height,
-                thickness,
-                ..
-            } => width * height - (width - 2.0 * thickness) * (height - 2.0 * thickness),
-            StructuralShape::Rod { radius, .. } => std::f64::consts::PI * radius * radius,
-            StructuralShape::Rectangle { width, height, .. } => width * height,
+    pub fn new(nodes: &[(f64, f64)], walls: &[(usize, usize, f64)]) -> ThinWalledShape {
+        assert!(
+            !walls.is_empty(),
+            "The section must have at least one wall."
+        );
+        for &(a, b, t) in walls {
+            assert!(
+                a < nodes.len() && b < nodes.len(),
+                "Wall ({}, {}) refers to a node past the last of the {} nodes.",
+                a,
+                b,
+                nodes.len()
+            );
+            let ((xa, ya), (xb, yb)) = (nodes[a], nodes[b]);
+            assert!(
+                (xb - xa).hypot(yb - ya) > 0.0,
+                "Wall ({}, {}) must have a nonzero length.",
+                a,
+                b
+            );
+            assert!(t > 0.0, "Wall ({}, {}) must be thicker than zero.", a, b);
+        }
+        let (area, moment_x, moment_y) =
+            walls
+                .iter()
+                .fold((0.0, 0.0, 0.0), |(area, moment_x, moment_y), &(a, b, t)| {
+                    let ((xa, ya), (xb, yb)) = (nodes[a], nodes[b]);
+                    let wall_area = (xb - xa).hypot(yb - ya) * t;
+                    (
+                        area + wall_area,
+                        moment_x + wall_area * (xa + xb) / 2.0,
+                        moment_y + wall_area * (ya + yb) / 2.0,
+                    )
+                });
+        let (cx, cy) = (moment_x / area, moment_y / area);
+        ThinWalledShape {
+            nodes: nodes
+                .iter()
+                .map(|&(x, y)| (meters(x - cx), meters(y - cy)))
+                .collect(),
+            walls: walls.iter().map(|&(a, b, t)| (a, b, meters(t))).collect(),
+            center_of_gravity: (meters(cx), meters(cy)),
+            rotation: radians(0.0),
         }
     }
 
-    /// A function to set the center of gravity of a shape
+    /// Make a new thin-walled section from a polyline along the centerline of its wall, with the
+    /// thickness of each segment. If the last point repeats the first, the section is closed. This
+    /// panics if there is not exactly one thickness for each segment, or if a segment would not
+    /// make a valid wall for `ThinWalledShape::new`.
     /// ```
-    /// # use structural_shapes::{StructuralShape};
-    /// let shape = StructuralShape::new_rod(2.0).with_cog(0.0, 0.0);
-    /// let moi = shape.moi_x();
+    /// # use structural_shapes::ThinWalledShape;
+    /// // A channel with a thicker web
+    /// let points = [(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+    /// let shape = ThinWalledShape::new_polyline(&points, &[0.01, 0.008, 0.01]);
     /// ```
-    pub fn with_cog(&mut self, x: f64, y: f64) -> StructuralShape {
-        self.set_cog((meters(x), meters(y)));
+    pub fn new_polyline(points: &[(f64, f64)], thicknesses: &[f64]) -> ThinWalledShape {
+        assert!(
+            thicknesses.len() + 1 == points.len(),
+            "A polyline of {} points needs {} thicknesses, one for each segment, but {} were given.",
+            points.len(),
+            points.len().saturating_sub(1),
+            thicknesses.len()
+        );
+        let closed = points.len() > 2 && points.first() == points.last();
+        let nodes = if closed {
+            &points[..points.len() - 1]
+        } else {
+            points
+        };
+        let walls: Vec<(usize, usize, f64)> = thicknesses
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| (i, (i + 1) % nodes.len(), t))
+            .collect();
+        ThinWalledShape::new(nodes, &walls)
+    }
+
+    /// This function returns the Saint-Venant torsion constant of the section, adding the
+    /// Bredt-Batho shear flow of each of its cells to the open-section terms of its walls
+    pub fn torsion_constant(&self) -> SecondAreaMomentofInertia {
+        meters_to_the_fourth(self.analysis().torsion_constant)
+    }
+
+    /// This function returns the warping constant of the section, integrating the sectorial
+    /// coordinates along its centerline
+    pub fn warping_constant(&self) -> WarpingConstant {
+        meters_to_the_sixth(self.analysis().warping_constant)
+    }
+
+    /// This function returns the coordinates of the shear center of the section
+    pub fn shear_center(&self) -> (Length, Length) {
+        let [x, y] = self.analysis().shear_center;
+        offset(
+            self.center_of_gravity,
+            self.rotation,
+            (meters(x), meters(y)),
+        )
+    }
+
+    /// This function returns the normalized sectorial coordinate about the shear center at each
+    /// node of the section
+    /// ```
+    /// # use structural_shapes::ThinWalledShape;
+    /// let points = [(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+    /// let shape = ThinWalledShape::new_polyline(&points, &[0.01, 0.01, 0.01]);
+    /// let omega = shape.sectorial_coordinates();
+    /// ```
+    pub fn sectorial_coordinates(&self) -> Vec<Area> {
+        self.analysis()
+            .sectorial
+            .iter()
+            .map(|&omega| Area::new::<square_meter>(omega))
+            .collect()
+    }
+
+    /// This function returns the monosymmetry parameter (Wagner coefficient) for bending about the
+    /// centroidal x-axis of the unrotated section, positive when the larger flange is on top
+    pub fn monosymmetry_parameter(&self) -> Length {
+        let boundary = self.boundary();
+        let (ix, _, _) = edge_moments(&boundary);
+        wagner(&boundary, ix, self.analysis().shear_center)
+    }
+
+    /// A function to set the center of gravity of a section
+    /// ```
+    /// # use structural_shapes::ThinWalledShape;
+    /// let points = [(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+    /// let shape = ThinWalledShape::new_polyline(&points, &[0.01; 3]).with_cog(0.0, 0.0);
+    /// ```
+    pub fn with_cog(&mut self, x: f64, y: f64) -> ThinWalledShape {
+        self.center_of_gravity = (meters(x), meters(y));
         self.clone()
     }
 
-    /// A function to return the current center of gravity for a shape
-    pub(crate) fn get_cog(&self) -> (Length, Length) {
-        match *self {
-            StructuralShape::Pipe {
-                center_of_gravity, ..
-            } => center_of_gravity,
-            StructuralShape::IBeam {
-                center_of_gravity, ..
-            } => center_of_gravity,
-            StructuralShape::BoxBeam {
-                center_of_gravity, ..
-            } => center_of_gravity,
-            StructuralShape::Rod {
-                center_of_gravity, ..
-            } => center_of_gravity,
-            StructuralShape::Rectangle {
-                center_of_gravity, ..
-            } => center_of_gravity,
-        }
+    /// A function to set the rotation of a section about its center of gravity, in radians
+    /// counterclockwise
+    /// ```
+    /// # use structural_shapes::ThinWalledShape;
+    /// let points = [(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+    /// let shape = ThinWalledShape::new_polyline(&points, &[0.01; 3]).with_rotation(1.0);
+    /// ```
+    pub fn with_rotation(&mut self, angle: f64) -> ThinWalledShape {
+        self.rotation = radians(angle);
+        self.clone()
     }
 
-    /// A function to set the current center of gravity for a shape
-    pub(crate) fn set_cog(&mut self, cog: (Length, Length)) {
-        match *self {
-            StructuralShape::Pipe {
-                ref mut center_of_gravity,
-                ..
-            } => {
-                *center_of_gravity = cog;
-            }
-            StructuralShape::IBeam {
-                ref mut center_of_gravity,
-                ..
-            } => {
-                *center_of_gravity = cog;
-            }
-            StructuralShape::BoxBeam {
-                ref mut center_of_gravity,
-                ..
-            } => {
-                *center_of_gravity = cog;
-            }
-            StructuralShape::Rod {
-                ref mut center_of_gravity,
-                ..
-            } => {
-                *center_of_gravity = cog;
-            }
-            StructuralShape::Rectangle {
-                ref mut center_of_gravity,
-                ..
-            } => {
-                *center_of_gravity = cog;
-            }
-        };
+    /// Outline of each wall of the unrotated section, counterclockwise and measured from the center
+    /// of gravity
+    fn rings(&self) -> Vec<Vec<(Length, Length)>> {
+        self.walls
+            .iter()
+            .map(|&(a, b, thickness)| {
+                let ((xa, ya), (xb, yb)) = (self.nodes[a], self.nodes[b]);
+                let length = (xb - xa).hypot(yb - ya);
+                // Half the thickness along the left-hand normal of the wall
+                let (nx, ny) = (
+                    -(yb - ya) / length * thickness / 2.0,
+                    (xb - xa) / length * thickness / 2.0,
+                );
+                vec![
+                    (xa - nx, ya - ny),
+                    (xb - nx, yb - ny),
+                    (xb + nx, yb + ny),
+                    (xa + nx, ya + ny),
+                ]
+            })
+            .collect()
+    }
+
+    /// Edges around the walls of the unrotated section, in meters from the center of gravity
+    fn boundary(&self) -> Vec<geometry::Edge> {
+        ring_edges(&self.rings())
+    }
+
+    /// Torsion and warping properties along the centerlines of the walls of the unrotated section
+    fn analysis(&self) -> thin_walled::Analysis {
+        let nodes: Vec<geometry::Point> = self
+            .nodes
+            .iter()
+            .map(|&(x, y)| [x.value, y.value])
+            .collect();
+        let walls: Vec<thin_walled::Wall> = self
+            .walls
+            .iter()
+            .map(|&(a, b, thickness)| (a, b, thickness.value))
+            .collect();
+        thin_walled::analyse(&nodes, &walls)
+    }
+
+    /// Distances from the center of gravity to the left, bottom, right and top edges of the walls
+    /// of the unrotated section
+    fn local_extents(&self) -> (Length, Length, Length, Length) {
+        extents(&self.rings().concat())
+    }
+
+    /// A composite holding only this section
+    fn composite(&self) -> CompositeShape {
+        CompositeShape::new().add_thin_walled(self.clone())
+    }
+}
+
+outline_properties!(ThinWalledShape, "section");
+
+/// A shape that can be added to or subtracted from a composite
+trait Member {
+    /// Cross-sectional area
+    fn area(&self) -> Area;
+    /// Moment of inertia around the x-axis
+    fn moi_x(&self) -> SecondAreaMomentofInertia;
+    /// Moment of inertia around the y-axis
+    fn moi_y(&self) -> SecondAreaMomentofInertia;
+    /// Product moment of inertia about the x- and y-axes
+    fn product_moi(&self) -> SecondAreaMomentofInertia;
+    /// Coordinates of the center of gravity
+    fn get_cog(&self) -> (Length, Length);
+    /// Move the center of gravity
+    fn set_cog(&mut self, cog: (Length, Length));
+    /// Lower-left and upper-right corners of the bounding box
+    fn bounding_box(&self) -> ((Length, Length), (Length, Length));
+    /// Area and first moment of area of the part beyond an axis-aligned line, as for
+    /// `StructuralShape::split`
+    fn split(&self, axis: Axis, offset: Length) -> (Area, Volume);
+}
+
+impl Member for StructuralShape {
+    fn area(&self) -> Area {
+        StructuralShape::area(self)
+    }
+    fn moi_x(&self) -> SecondAreaMomentofInertia {
+        StructuralShape::moi_x(self)
+    }
+    fn moi_y(&self) -> SecondAreaMomentofInertia {
+        StructuralShape::moi_y(self)
+    }
+    fn product_moi(&self) -> SecondAreaMomentofInertia {
+        StructuralShape::product_moi(self)
+    }
+    fn get_cog(&self) -> (Length, Length) {
+        StructuralShape::get_cog(self)
+    }
+    fn set_cog(&mut self, cog: (Length, Length)) {
+        StructuralShape::set_cog(self, cog)
+    }
+    fn bounding_box(&self) -> ((Length, Length), (Length, Length)) {
+        StructuralShape::bounding_box(self)
+    }
+    fn split(&self, axis: Axis, offset: Length) -> (Area, Volume) {
+        StructuralShape::split(self, axis, offset)
     }
 }
 
@@ -405,6 +3973,10 @@ impl StructuralShape {
 pub struct CompositeShape {
     /// Constituent shapes
     pub shapes: Vec<(i8, StructuralShape)>,
+    /// Constituent polygons
+    pub polygons: Vec<(i8, PolygonShape)>,
+    /// Constituent thin-walled sections
+    pub thin_walled: Vec<(i8, ThinWalledShape)>,
 }
 
 impl CompositeShape {
@@ -422,24 +3994,36 @@ impl CompositeShape {
         self.shapes.push((-1, new_shape));
         self.clone()
     }
+    /// This function adds a polygon to the composite
+    pub fn add_polygon(&mut self, polygon: PolygonShape) -> Self {
+        self.polygons.push((1, polygon));
+        self.clone()
+    }
+    /// This function subtracts a polygon from the composite
+    pub fn sub_polygon(&mut self, polygon: PolygonShape) -> Self {
+        self.polygons.push((-1, polygon));
+        self.clone()
+    }
+    /// This function adds a thin-walled section to the composite
+    pub fn add_thin_walled(&mut self, section: ThinWalledShape) -> Self {
+        self.thin_walled.push((1, section));
+        self.clone()
+    }
+    /// This function subtracts a thin-walled section from the composite
+    pub fn sub_thin_walled(&mut self, section: ThinWalledShape) -> Self {
+        self.thin_walled.push((-1, section));
+        self.clone()
+    }
     /// Calculate center of gravity and update COG of members
     pub fn calculate_cog(&self) -> (Length, Length) {
         let area = self.area();
         let area_times_cx: Volume = self
-            .shapes
-            .iter()
-            .map(|x| {
-                let center_of_gravity = x.1.get_cog();
-                (x.0 as f64) * x.1.area() * center_of_gravity.0
-            })
+            .members()
+            .map(|(sign, member)| sign * member.area() * member.get_cog().0)
             .sum();
         let area_times_cy: Volume = self
-            .shapes
-            .iter()
-            .map(|x| {
-                let center_of_gravity = x.1.get_cog();
-                (x.0 as f64) * x.1.area() * center_of_gravity.1
-            })
+            .members()
+            .map(|(sign, member)| sign * member.area() * member.get_cog().1)
             .sum();
         let cog_x = area_times_cx / area;
         let cog_y = area_times_cy / area;
@@ -448,71 +4032,555 @@ impl CompositeShape {
     /// Shift structure to have cog at (0.0,0.0)
     pub fn update_cog(&mut self) {
         let (cog_x, cog_y) = self.calculate_cog();
-        self.shapes.iter_mut().for_each(|x| {
-            let (_, ref mut shape) = x;
-            let (old_x, old_y) = shape.get_cog();
-            shape.set_cog((old_x - cog_x, old_y - cog_y));
-        });
+        let shapes = self.shapes.iter_mut().map(|x| &mut x.1 as &mut dyn Member);
+        let polygons = self
+            .polygons
+            .iter_mut()
+            .map(|x| &mut x.1 as &mut dyn Member);
+        let thin_walled = self
+            .thin_walled
+            .iter_mut()
+            .map(|x| &mut x.1 as &mut dyn Member);
+        shapes
+            .chain(polygons)
+            .chain(thin_walled)
+            .for_each(|member| {
+                let (old_x, old_y) = member.get_cog();
+                member.set_cog((old_x - cog_x, old_y - cog_y));
+            });
+    }
+    /// Every member of the composite, with 1 for added members and -1 for subtracted ones
+    fn members(&self) -> impl Iterator<Item = (f64, &dyn Member)> + '_ {
+        let shapes = self
+            .shapes
+            .iter()
+            .map(|x| (x.0 as f64, &x.1 as &dyn Member));
+        let polygons = self
+            .polygons
+            .iter()
+            .map(|x| (x.0 as f64, &x.1 as &dyn Member));
+        let thin_walled = self
+            .thin_walled
+            .iter()
+            .map(|x| (x.0 as f64, &x.1 as &dyn Member));
+        shapes.chain(polygons).chain(thin_walled)
     }
 
     /// This function returns the moment of inertia of the composite shape around the x-axis
     pub fn moi_x(&self) -> SecondAreaMomentofInertia {
-        self.shapes.iter().map(|x| (x.0 as f64) * x.1.moi_x()).sum()
+        self.members()
+            .map(|(sign, member)| sign * member.moi_x())
+            .sum()
     }
     /// This function returns the moment of inertia of the composite shape around the y-axis
     pub fn moi_y(&self) -> SecondAreaMomentofInertia {
-        self.shapes.iter().map(|x| (x.0 as f64) * x.1.moi_y()).sum()
+        self.members()
+            .map(|(sign, member)| sign * member.moi_y())
+            .sum()
+    }
+    /// This function returns the product moment of inertia of the composite shape about the x- and y-axes
+    pub fn product_moi(&self) -> SecondAreaMomentofInertia {
+        self.members()
+            .map(|(sign, member)| sign * member.product_moi())
+            .sum()
     }
     /// This function returns the polar moment of inertia of the composite shape around the origin.
     pub fn polar_moi(&self) -> SecondAreaMomentofInertia {
         self.moi_x() + self.moi_y()
     }
+    /// This function returns the angle from the x-axis to the major principal axis, measured
+    /// counterclockwise about the center of gravity of the composite
+    pub fn principal_angle(&self) -> Angle {
+        let (ix, iy, ixy) = self.centroidal_moments();
+        principal_axes(ix, iy, ixy).0
+    }
+    /// This function returns the major and minor principal moments of inertia about the center of
+    /// gravity of the composite
+    pub fn principal_moi(&self) -> (SecondAreaMomentofInertia, SecondAreaMomentofInertia) {
+        let (ix, iy, ixy) = self.centroidal_moments();
+        let (_, i1, i2) = principal_axes(ix, iy, ixy);
+        (i1, i2)
+    }
+    /// This function returns the radius of gyration about the centroidal x-axis of the composite
+    pub fn radius_of_gyration_x(&self) -> Length {
+        let (ix, _, _) = self.centroidal_moments();
+        (ix / self.area()).sqrt()
+    }
+    /// This function returns the radius of gyration about the centroidal y-axis of the composite
+    pub fn radius_of_gyration_y(&self) -> Length {
+        let (_, iy, _) = self.centroidal_moments();
+        (iy / self.area()).sqrt()
+    }
+    /// This function returns the least radius of gyration of the composite, about the minor
+    /// principal axis
+    pub fn radius_of_gyration_min(&self) -> Length {
+        (self.principal_moi().1 / self.area()).sqrt()
+    }
+    /// This function returns the radii of gyration of the composite about its major and minor
+    /// principal axes
+    pub fn principal_radius_of_gyration(&self) -> (Length, Length) {
+        let (i1, i2) = self.principal_moi();
+        ((i1 / self.area()).sqrt(), (i2 / self.area()).sqrt())
+    }
+    /// This function returns the slenderness ratio KL/r of the composite for an effective length
+    /// KL, using the least radius of gyration
+    pub fn slenderness(&self, effective_length: Length) -> f64 {
+        (effective_length / self.radius_of_gyration_min()).value
+    }
+    /// This function returns the moment of inertia of the composite about the horizontal axis
+    /// through its center of gravity
+    pub fn centroidal_moi_x(&self) -> SecondAreaMomentofInertia {
+        let (_, cy) = self.calculate_cog();
+        self.moi_x() - self.area() * cy * cy
+    }
+    /// This function returns the moment of inertia of the composite about the vertical axis
+    /// through its center of gravity
+    pub fn centroidal_moi_y(&self) -> SecondAreaMomentofInertia {
+        let (cx, _) = self.calculate_cog();
+        self.moi_y() - self.area() * cx * cx
+    }
+    /// This function returns the product moment of inertia of the composite about the axes
+    /// through its center of gravity
+    pub fn centroidal_product_moi(&self) -> SecondAreaMomentofInertia {
+        let (cx, cy) = self.calculate_cog();
+        self.product_moi() - self.area() * cx * cy
+    }
+    /// This function returns the moment of inertia of the composite about an axis through `point`,
+    /// inclined at `angle` counterclockwise from the x-axis
+    pub fn moi_about(&self, point: (Length, Length), angle: Angle) -> SecondAreaMomentofInertia {
+        let moments = self.centroidal_moments();
+        moi_about(self.area(), self.calculate_cog(), moments, point, angle)
+    }
+    /// Moments and product of inertia about axes through the center of gravity of the composite
+    fn centroidal_moments(
+        &self,
+    ) -> (
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+    ) {
+        (
+            self.centroidal_moi_x(),
+            self.centroidal_moi_y(),
+            self.centroidal_product_moi(),
+        )
+    }
     /// This function returns the area of the composite shape
     pub fn area(&self) -> Area {
-        self.shapes.iter().map(|x| (x.0 as f64) * x.1.area()).sum()
+        self.members()
+            .map(|(sign, member)| sign * member.area())
+            .sum()
+    }
+    /// This function returns the location of the plastic neutral axes, which divide the area of
+    /// the composite in half. The first element is the x-coordinate of the axis for bending about
+    /// the y-axis and the second is the y-coordinate of the axis for bending about the x-axis.
+    pub fn plastic_neutral_axis(&self) -> (Length, Length) {
+        (self.plastic_axis(Axis::Y).0, self.plastic_axis(Axis::X).0)
+    }
+    /// This function returns the plastic section modulus of the composite for bending about the x-axis
+    pub fn plastic_modulus_x(&self) -> Volume {
+        self.plastic_axis(Axis::X).1
+    }
+    /// This function returns the plastic section modulus of the composite for bending about the y-axis
+    pub fn plastic_modulus_y(&self) -> Volume {
+        self.plastic_axis(Axis::Y).1
+    }
+    /// This function returns the shape factor of the composite for bending about the x-axis, the
+    /// ratio of the plastic section modulus to the smaller elastic section modulus
+    pub fn shape_factor_x(&self) -> f64 {
+        let (top, bottom) = self.section_modulus_x();
+        (self.plastic_modulus_x() / top.min(bottom)).value
+    }
+    /// This function returns the shape factor of the composite for bending about the y-axis, the
+    /// ratio of the plastic section modulus to the smaller elastic section modulus
+    pub fn shape_factor_y(&self) -> f64 {
+        let (left, right) = self.section_modulus_y();
+        (self.plastic_modulus_y() / left.min(right)).value
+    }
+    /// Area and first moment of area of the part of the composite beyond an axis-aligned line
+    fn split(&self, axis: Axis, offset: Length) -> (Area, Volume) {
+        self.members()
+            .map(|(sign, member)| {
+                let (area, moment) = member.split(axis, offset);
+                (sign * area, sign * moment)
+            })
+            .fold((Area::default(), Volume::default()), |a, b| {
+                (a.0 + b.0, a.1 + b.1)
+            })
+    }
+    /// Find the plastic neutral axis by bisection and return its location with the plastic modulus
+    fn plastic_axis(&self, axis: Axis) -> (Length, Volume) {
+        let (area, moment) = self.split(axis, Length::new::<meter>(f64::NEG_INFINITY));
+        let (lower, upper) = self.bounding_box();
+        let (mut low, mut high) = match axis {
+            Axis::X => (lower.1, upper.1),
+            Axis::Y => (lower.0, upper.0),
+        };
+        // Halve the interval until it is as fine as the coordinates allow, in about 50 steps
+        let tolerance = 4.0 * f64::EPSILON * (high - low).max(low.abs()).max(high.abs());
+        while high - low > tolerance {
+            let middle = (low + high) / 2.0;
+            if self.split(axis, middle).0 > area / 2.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        let neutral_axis = (low + high) / 2.0;
+        let (area_beyond, moment_beyond) = self.split(axis, neutral_axis);
+        let modulus = (moment_beyond - neutral_axis * area_beyond)
+            + (neutral_axis * (area - area_beyond) - (moment - moment_beyond));
+        (neutral_axis, modulus)
+    }
+    /// This function returns the lower-left and upper-right corners of the bounding box of the
+    /// composite shape. Subtracted shapes are assumed to lie within the added ones, and a composite
+    /// with no added shapes has an empty box at the origin.
+    pub fn bounding_box(&self) -> ((Length, Length), (Length, Length)) {
+        self.members()
+            .filter(|(sign, _)| *sign > 0.0)
+            .map(|(_, member)| member.bounding_box())
+            .reduce(|a, b| {
+                (
+                    ((a.0).0.min((b.0).0), (a.0).1.min((b.0).1)),
+                    ((a.1).0.max((b.1).0), (a.1).1.max((b.1).1)),
+                )
+            })
+            .unwrap_or_default()
+    }
+    /// This function returns the elastic section moduli for bending about the centroidal x-axis of
+    /// the composite, for the top and bottom fibres respectively
+    pub fn section_modulus_x(&self) -> (Volume, Volume) {
+        let (ix, _, _) = self.centroidal_moments();
+        let ((_, y_min), (_, y_max)) = self.bounding_box();
+        let (_, cy) = self.calculate_cog();
+        (ix / (y_max - cy), ix / (cy - y_min))
+    }
+    /// This function returns the elastic section moduli for bending about the centroidal y-axis of
+    /// the composite, for the left and right fibres respectively
+    pub fn section_modulus_y(&self) -> (Volume, Volume) {
+        let (_, iy, _) = self.centroidal_moments();
+        let ((x_min, _), (x_max, _)) = self.bounding_box();
+        let (cx, _) = self.calculate_cog();
+        (iy / (cx - x_min), iy / (x_max - cx))
     }
 }
 
 /// Implement default
 impl Default for CompositeShape {
     fn default() -> Self {
-        CompositeShape { shapes: vec![] }
+        CompositeShape {
+            shapes: vec![],
+            polygons: vec![],
+            thin_walled: vec![],
+        }
+    }
+}
+
+/// Find the principal angle and the major and minor principal moments from centroidal moments
+fn principal_axes(
+    ix: SecondAreaMomentofInertia,
+    iy: SecondAreaMomentofInertia,
+    ixy: SecondAreaMomentofInertia,
+) -> (Angle, SecondAreaMomentofInertia, SecondAreaMomentofInertia) {
+    let average = (ix + iy) / 2.0;
+    let radius = ((ix - iy) / 2.0).hypot(ixy);
+    let angle = if radius.value == 0.0 {
+        Angle::new::<radian>(0.0)
+    } else {
+        (-2.0 * ixy).atan2(ix - iy) / 2.0
+    };
+    // Report the axis in (-pi/2, pi/2]
+    let angle = if angle.value <= -std::f64::consts::FRAC_PI_2 {
+        angle + Angle::new::<radian>(std::f64::consts::PI)
+    } else {
+        angle
+    };
+    (angle, average + radius, average - radius)
+}
+
+/// The axis about which a shape is bent
+#[derive(Clone, Copy, Debug)]
+enum Axis {
+    /// Bending about the x-axis, with fibres distributed along y
+    X,
+    /// Bending about the y-axis, with fibres distributed along x
+    Y,
+}
+
+impl Axis {
+    /// Counterclockwise rotation in radians that brings the coordinate distributed across the axis
+    /// onto the y-axis
+    fn turn(self) -> f64 {
+        match self {
+            Axis::X => 0.0,
+            Axis::Y => std::f64::consts::FRAC_PI_2,
+        }
+    }
+
+    /// Select the coordinate that is distributed across the axis
+    fn select(self, pair: (Length, Length)) -> Length {
+        match self {
+            Axis::X => pair.1,
+            Axis::Y => pair.0,
+        }
     }
 }
 
-/// Function for swapping values
-fn swap(pair: (Length, Length)) -> (Length, Length) {
-    (pair.1, pair.0)
+/// Move centroidal moments to an axis through `point` inclined at `angle` to the x-axis
+fn moi_about(
+    area: Area,
+    center_of_gravity: (Length, Length),
+    (ix, iy, ixy): (
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+    ),
+    point: (Length, Length),
+    angle: Angle,
+) -> SecondAreaMomentofInertia {
+    let dx = center_of_gravity.0 - point.0;
+    let dy = center_of_gravity.1 - point.1;
+    let (sin, cos) = angle.value.sin_cos();
+    (ix + area * dy * dy) * cos * cos + (iy + area * dx * dx) * sin * sin
+        - 2.0 * (ixy + area * dx * dy) * sin * cos
+}
+
+/// Turn moments and product of inertia about the centroidal axes of an unrotated shape by its
+/// counterclockwise rotation
+fn rotate_moments(
+    (ix, iy, ixy): (
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+        SecondAreaMomentofInertia,
+    ),
+    rotation: Angle,
+) -> (
+    SecondAreaMomentofInertia,
+    SecondAreaMomentofInertia,
+    SecondAreaMomentofInertia,
+) {
+    let (sin, cos) = rotation.value.sin_cos();
+    (
+        ix * cos * cos + iy * sin * sin + 2.0 * ixy * sin * cos,
+        ix * sin * sin + iy * cos * cos - 2.0 * ixy * sin * cos,
+        (iy - ix) * sin * cos + ixy * (cos * cos - sin * sin),
+    )
+}
+
+/// Edges around rings of vertices, the first running counterclockwise around the outside and any
+/// others clockwise around holes
+fn ring_edges(rings: &[Vec<(Length, Length)>]) -> Vec<geometry::Edge> {
+    let rings: Vec<Vec<geometry::Point>> = rings
+        .iter()
+        .map(|ring| ring.iter().map(|&(x, y)| [x.value, y.value]).collect())
+        .collect();
+    geometry::edges(&rings)
+}
+
+/// Moments and product of inertia of the area inside edges measured in meters from its center of
+/// gravity
+fn edge_moments(
+    boundary: &[geometry::Edge],
+) -> (
+    SecondAreaMomentofInertia,
+    SecondAreaMomentofInertia,
+    SecondAreaMomentofInertia,
+) {
+    (
+        meters_to_the_fourth(geometry::edge_moment(boundary, 0, 2)),
+        meters_to_the_fourth(geometry::edge_moment(boundary, 2, 0)),
+        meters_to_the_fourth(geometry::edge_moment(boundary, 1, 1)),
+    )
+}
+
+/// Lower-left and upper-right corners of the bounding box of edges measured in meters from the
+/// center of gravity of an unrotated shape, once the shape is placed
+fn edge_box(
+    boundary: &[geometry::Edge],
+    center_of_gravity: (Length, Length),
+    rotation: Angle,
+) -> ((Length, Length), (Length, Length)) {
+    let (cx, cy) = center_of_gravity;
+    let boundary: Vec<geometry::Edge> = boundary
+        .iter()
+        .map(|edge| edge.place(rotation.value, [cx.value, cy.value]))
+        .collect();
+    let (lower, upper) = geometry::edge_bounds(&boundary);
+    (
+        (meters(lower[0]), meters(lower[1])),
+        (meters(upper[0]), meters(upper[1])),
+    )
+}
+
+/// Area and first moment of area of the part of a placed shape beyond an axis-aligned line, as for
+/// `StructuralShape::split`, from edges measured in meters from the center of gravity of the
+/// unrotated shape
+fn split_edges(
+    boundary: &[geometry::Edge],
+    center_of_gravity: (Length, Length),
+    rotation: Angle,
+    axis: Axis,
+    offset: Length,
+) -> (Area, Volume) {
+    // Turn the placed shape so that the line to split it along is horizontal
+    let (cx, cy) = center_of_gravity;
+    let boundary: Vec<geometry::Edge> = boundary
+        .iter()
+        .map(|edge| {
+            edge.place(rotation.value, [cx.value, cy.value])
+                .place(axis.turn(), [0.0, 0.0])
+        })
+        .collect();
+    let part = geometry::clip_edges(&boundary, offset.value);
+    (
+        Area::new::<square_meter>(geometry::edge_moment(&part, 0, 0)),
+        Volume::new::<cubic_meter>(geometry::edge_moment(&part, 0, 1)),
+    )
+}
+
+/// Saint-Venant's approximation for the torsion constant of a compact solid section, from its
+/// area and centroidal moments of inertia
+fn compact_torsion(
+    area: Area,
+    ix: SecondAreaMomentofInertia,
+    iy: SecondAreaMomentofInertia,
+) -> SecondAreaMomentofInertia {
+    area * area * area * area / (40.0 * (ix + iy))
+}
+
+/// Monosymmetry parameter from edges measured in meters from the center of gravity, in the frame
+/// of the axis of bending, with the moment of inertia about that axis and the shear center in the
+/// same frame
+fn wagner(
+    boundary: &[geometry::Edge],
+    moi: SecondAreaMomentofInertia,
+    shear_center: geometry::Point,
+) -> Length {
+    let integral = geometry::edge_moment(boundary, 2, 1) + geometry::edge_moment(boundary, 0, 3);
+    meters(2.0 * shear_center[1] - integral / moi.value)
+}
+
+/// Half the width and height of the bounding box of a rotated ellipse
+fn ellipse_reach(width: Length, height: Length, rotation: Angle) -> (Length, Length) {
+    let (sin, cos) = rotation.value.sin_cos();
+    let (a, b) = (width.value / 2.0, height.value / 2.0);
+    (
+        meters((a * cos).hypot(b * sin)),
+        meters((a * sin).hypot(b * cos)),
+    )
+}
+
+/// Vertices of a polygon measured from its center of gravity
+fn centered(polygon: &[geometry::Point]) -> Vec<(Length, Length)> {
+    let (area, moment_x, moment_y) = geometry::area_and_moments(polygon);
+    polygon
+        .iter()
+        .map(|&[x, y]| (meters(x - moment_x / area), meters(y - moment_y / area)))
+        .collect()
+}
+
+/// Corners of a regular polygon around its center, counterclockwise from the right end of the flat
+/// at the bottom
+fn regular_polygon(sides: usize, radius: Length) -> Vec<(Length, Length)> {
+    let step = 2.0 * std::f64::consts::PI / sides as f64;
+    (0..sides)
+        .map(|i| {
+            let angle = (i as f64 + 0.5) * step - std::f64::consts::FRAC_PI_2;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Distance from the center of a regular polygon to each corner, from the distance across its flats
+fn circumradius(sides: usize, across_flats: f64) -> f64 {
+    across_flats / 2.0 / (std::f64::consts::PI / sides as f64).cos()
+}
+
+/// Distances from the origin to the left, bottom, right and top of a set of points, as for
+/// `local_extents`
+fn extents(points: &[(Length, Length)]) -> (Length, Length, Length, Length) {
+    let zero = Length::default();
+    points.iter().fold(
+        (zero, zero, zero, zero),
+        |(left, bottom, right, top), &(x, y)| {
+            (left.max(-x), bottom.max(-y), right.max(x), top.max(y))
+        },
+    )
+}
+
+/// Area of the spandrel left between a square corner and a fillet of `radius`, the distance of its
+/// center of gravity from either edge of the corner, and its moment of inertia about its own axis
+/// parallel to an edge
+fn spandrel(radius: Length) -> (Area, Length, SecondAreaMomentofInertia) {
+    let pi = std::f64::consts::PI;
+    let area = (1.0 - pi / 4.0) * radius * radius;
+    let distance = (10.0 - 3.0 * pi) / (12.0 - 3.0 * pi) * radius;
+    let moi =
+        (1.0 - 5.0 * pi / 16.0) * radius * radius * radius * radius - area * distance * distance;
+    (area, distance, moi)
+}
+
+/// Convert a second moment of area in meters to the fourth power
+fn meters_to_the_fourth(value: f64) -> SecondAreaMomentofInertia {
+    Area::new::<square_meter>(value) * Area::new::<square_meter>(1.0)
+}
+
+/// Convert a warping constant in meters to the sixth power
+fn meters_to_the_sixth(value: f64) -> WarpingConstant {
+    Volume::new::<cubic_meter>(value) * Volume::new::<cubic_meter>(1.0)
 }
 
-/// Create a composite I-beam from some initial parameters
-fn composite_ibeam(
+/// Move from the center of gravity of a shape by an offset given in the shape's own rotated frame
+fn offset(
+    center_of_gravity: (Length, Length),
+    rotation: Angle,
+    (dx, dy): (Length, Length),
+) -> (Length, Length) {
+    let (sin, cos) = rotation.value.sin_cos();
+    (
+        center_of_gravity.0 + dx * cos - dy * sin,
+        center_of_gravity.1 + dx * sin + dy * cos,
+    )
+}
+
+/// Create a composite pipe from some initial parameters
+fn composite_pipe(
+    outer_radius: Length,
+    thickness: Length,
+    center_of_gravity: (Length, Length),
+) -> CompositeShape {
+    CompositeShape::new()
+        .add(StructuralShape::Rod {
+            radius: outer_radius,
+            center_of_gravity,
+            rotation: radians(0.0),
+        })
+        .sub(StructuralShape::Rod {
+            radius: (outer_radius - thickness),
+            center_of_gravity,
+            rotation: radians(0.0),
+        })
+}
+
+/// Create a composite elliptical hollow section from some initial parameters
+fn composite_elliptical_hollow(
     width: Length,
     height: Length,
-    web_thickness: Length,
-    flange_thickness: Length,
+    thickness: Length,
     center_of_gravity: (Length, Length),
+    rotation: Angle,
 ) -> CompositeShape {
     CompositeShape::new()
-        .add(StructuralShape::Rectangle {
+        .add(StructuralShape::Ellipse {
             width,
             height,
             center_of_gravity,
+            rotation,
         })
-        .sub(StructuralShape::Rectangle {
-            width: ((width - web_thickness) / 2.0),
-            height: (height - 2.0 * flange_thickness),
-            center_of_gravity: (
-                center_of_gravity.0 - ((width - web_thickness) / 4.0) - web_thickness / 2.0,
-                center_of_gravity.1,
-            ),
-        })
-        .sub(StructuralShape::Rectangle {
-            width: ((width - web_thickness) / 2.0),
-            height: (height - 2.0 * flange_thickness),
-            center_of_gravity: (
-                center_of_gravity.0 + ((width - web_thickness) / 4.0) + web_thickness / 2.0,
-                center_of_gravity.1,
-            ),
+        .sub(StructuralShape::Ellipse {
+            width: width - 2.0 * thickness,
+            height: height - 2.0 * thickness,
+            center_of_gravity,
+            rotation,
         })
 }
@@ -0,0 +1,264 @@
+//! Thin-walled sections made of straight walls between nodes, analysed along their centerlines in
+//! plain meters
+
+use crate::geometry::{cross, Point};
+
+/// A straight wall from one node to another, with a thickness
+pub(crate) type Wall = (usize, usize, f64);
+
+/// Torsion and warping properties of a thin-walled section
+pub(crate) struct Analysis {
+    /// Saint-Venant torsion constant, including the Bredt-Batho contribution of any closed cells
+    pub(crate) torsion_constant: f64,
+    /// Location of the shear center
+    pub(crate) shear_center: Point,
+    /// Normalized sectorial coordinate at each node, about the shear center
+    pub(crate) sectorial: Vec<f64>,
+    /// Warping constant
+    pub(crate) warping_constant: f64,
+}
+
+/// Analyse a thin-walled section. Closed cells are found from the faces of the network of walls,
+/// so walls must only meet at nodes.
+pub(crate) fn analyse(nodes: &[Point], walls: &[Wall]) -> Analysis {
+    let lengths: Vec<f64> = walls
+        .iter()
+        .map(|&(a, b, _)| (nodes[b][0] - nodes[a][0]).hypot(nodes[b][1] - nodes[a][1]))
+        .collect();
+    let flexibility: Vec<f64> = walls
+        .iter()
+        .zip(&lengths)
+        .map(|(wall, length)| length / wall.2)
+        .collect();
+
+    // Saint-Venant shear flow around each cell for a unit rate of twist, from the compatibility
+    // of warping around every cell
+    let cells = cells(nodes, walls);
+    let matrix: Vec<Vec<f64>> = cells
+        .iter()
+        .map(|(_, row)| {
+            cells
+                .iter()
+                .map(|(_, column)| {
+                    let mut sum = 0.0;
+                    for &(i, sign) in row {
+                        for &(j, other) in column {
+                            if i == j {
+                                sum += sign * other * flexibility[i];
+                            }
+                        }
+                    }
+                    sum
+                })
+                .collect()
+        })
+        .collect();
+    let twice_areas: Vec<f64> = cells.iter().map(|(area, _)| 2.0 * area).collect();
+    let flows = solve(matrix, twice_areas.clone());
+    let mut wall_flows = vec![0.0; walls.len()];
+    for ((_, boundary), flow) in cells.iter().zip(&flows) {
+        for &(i, sign) in boundary {
+            wall_flows[i] += sign * flow;
+        }
+    }
+    let torsion_constant = walls
+        .iter()
+        .zip(&lengths)
+        .map(|(wall, length)| length * wall.2 * wall.2 * wall.2 / 3.0)
+        .sum::<f64>()
+        + twice_areas
+            .iter()
+            .zip(&flows)
+            .map(|(twice_area, flow)| twice_area * flow)
+            .sum::<f64>();
+
+    // Integral over the walls of the product of two quantities that vary linearly along each wall
+    let integral = |a: &[f64], b: &[f64]| -> f64 {
+        walls
+            .iter()
+            .zip(&lengths)
+            .map(|(&(i, j, thickness), length)| {
+                thickness
+                    * length
+                    * (2.0 * a[i] * b[i] + 2.0 * a[j] * b[j] + a[i] * b[j] + a[j] * b[i])
+                    / 6.0
+            })
+            .sum()
+    };
+    let ones = vec![1.0; nodes.len()];
+    let area = integral(&ones, &ones);
+    let x: Vec<f64> = nodes.iter().map(|node| node[0]).collect();
+    let y: Vec<f64> = nodes.iter().map(|node| node[1]).collect();
+    let (cx, cy) = (integral(&x, &ones) / area, integral(&y, &ones) / area);
+    let x: Vec<f64> = x.iter().map(|value| value - cx).collect();
+    let y: Vec<f64> = y.iter().map(|value| value - cy).collect();
+    let (ix, iy, ixy) = (integral(&y, &y), integral(&x, &x), integral(&x, &y));
+
+    // Sectorial coordinates about a pole, less the part taken up by the shear flow in closed cells
+    // so that they are single-valued, spreading out from the first node of each connected part
+    let sectorial = |pole: Point| -> Vec<f64> {
+        let mut omega: Vec<Option<f64>> = vec![None; nodes.len()];
+        for root in 0..nodes.len() {
+            if omega[root].is_some() {
+                continue;
+            }
+            omega[root] = Some(0.0);
+            let mut queue = vec![root];
+            while let Some(node) = queue.pop() {
+                for (i, &(a, b, thickness)) in walls.iter().enumerate() {
+                    let (next, sign) = match node {
+                        _ if node == a => (b, 1.0),
+                        _ if node == b => (a, -1.0),
+                        _ => continue,
+                    };
+                    if omega[next].is_none() {
+                        let start = [nodes[node][0] - pole[0], nodes[node][1] - pole[1]];
+                        let end = [nodes[next][0] - pole[0], nodes[next][1] - pole[1]];
+                        let swept =
+                            cross(start, end) - sign * wall_flows[i] * lengths[i] / thickness;
+                        omega[next] = omega[node].map(|value| value + swept);
+                        queue.push(next);
+                    }
+                }
+            }
+        }
+        omega
+            .into_iter()
+            .map(|value| value.unwrap_or(0.0))
+            .collect()
+    };
+    let omega = sectorial([cx, cy]);
+    let (i_omega_x, i_omega_y) = (integral(&omega, &x), integral(&omega, &y));
+    let determinant = ix * iy - ixy * ixy;
+    let shear_center = [
+        cx + (iy * i_omega_y - ixy * i_omega_x) / determinant,
+        cy - (ix * i_omega_x - ixy * i_omega_y) / determinant,
+    ];
+    let omega = sectorial(shear_center);
+    let mean = integral(&omega, &ones) / area;
+    let sectorial: Vec<f64> = omega.iter().map(|value| value - mean).collect();
+    let warping_constant = integral(&sectorial, &sectorial);
+    Analysis {
+        torsion_constant,
+        shear_center,
+        sectorial,
+        warping_constant,
+    }
+}
+
+/// Closed cells of a section, each with its enclosed area and the walls around it. Each wall is
+/// paired with 1 if the cell runs counterclockwise from its first node to its second, or -1 if it
+/// runs the other way.
+fn cells(nodes: &[Point], walls: &[Wall]) -> Vec<(f64, Vec<(usize, f64)>)> {
+    // Walls that do not lie on any closed loop carry no circulating shear flow
+    let looped: Vec<bool> = (0..walls.len())
+        .map(|i| connected(walls, i, walls[i].0, walls[i].1))
+        .collect();
+    // Walls leaving each node, in counterclockwise order
+    let mut neighbours: Vec<Vec<(usize, usize)>> = vec![vec![]; nodes.len()];
+    for (i, &(a, b, _)) in walls.iter().enumerate().filter(|&(i, _)| looped[i]) {
+        neighbours[a].push((b, i));
+        neighbours[b].push((a, i));
+    }
+    for (node, list) in neighbours.iter_mut().enumerate() {
+        let angle = |&(other, _): &(usize, usize)| {
+            (nodes[other][1] - nodes[node][1]).atan2(nodes[other][0] - nodes[node][0])
+        };
+        list.sort_by(|p, q| {
+            angle(p)
+                .partial_cmp(&angle(q))
+                .expect("Nodes must be finite.")
+        });
+    }
+    // Trace each face by turning as far left as possible at every node, which keeps the face on
+    // the left so that the cells run counterclockwise and the outside runs clockwise
+    let mut visited = vec![[false; 2]; walls.len()];
+    let mut cells = vec![];
+    for (start, &(a, b, _)) in walls.iter().enumerate() {
+        for &(first, second, side) in &[(a, b, 0), (b, a, 1)] {
+            if !looped[start] || visited[start][side] {
+                continue;
+            }
+            let (mut from, mut to, mut wall) = (first, second, start);
+            let mut boundary = vec![];
+            let mut area = 0.0;
+            loop {
+                let forward = walls[wall].0 == from;
+                visited[wall][if forward { 0 } else { 1 }] = true;
+                boundary.push((wall, if forward { 1.0 } else { -1.0 }));
+                area += cross(nodes[from], nodes[to]) / 2.0;
+                let list = &neighbours[to];
+                let position = list
+                    .iter()
+                    .position(|&(other, i)| other == from && i == wall)
+                    .expect("Every wall is listed at both of its nodes.");
+                let (next, next_wall) = list[(position + list.len() - 1) % list.len()];
+                from = to;
+                to = next;
+                wall = next_wall;
+                if wall == start && from == first {
+                    break;
+                }
+            }
+            if area > 0.0 {
+                cells.push((area, boundary));
+            }
+        }
+    }
+    cells
+}
+
+/// Whether two nodes are connected by the walls without using wall `skip`
+fn connected(walls: &[Wall], skip: usize, from: usize, to: usize) -> bool {
+    let mut reached = vec![from];
+    let mut queue = vec![from];
+    while let Some(node) = queue.pop() {
+        for (i, &(a, b, _)) in walls.iter().enumerate() {
+            let next = match node {
+                _ if i == skip => continue,
+                _ if node == a => b,
+                _ if node == b => a,
+                _ => continue,
+            };
+            if next == to {
+                return true;
+            }
+            if !reached.contains(&next) {
+                reached.push(next);
+                queue.push(next);
+            }
+        }
+    }
+    false
+}
+
+/// Solve a small linear system by Gaussian elimination with partial pivoting
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Vec<f64> {
+    let n = rhs.len();
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|&i, &j| {
+                matrix[i][column]
+                    .abs()
+                    .partial_cmp(&matrix[j][column].abs())
+                    .expect("The system must be finite.")
+            })
+            .unwrap_or(column);
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        for row in column + 1..n {
+            let factor = matrix[row][column] / matrix[column][column];
+            let (above, below) = matrix.split_at_mut(row);
+            for (value, pivot) in below[0][column..].iter_mut().zip(&above[column][column..]) {
+                *value -= factor * pivot;
+            }
+            rhs[row] -= factor * rhs[column];
+        }
+    }
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - known) / matrix[row][row];
+    }
+    solution
+}
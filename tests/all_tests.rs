@@ -1,12 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use structural_shapes::{meters, CompositeShape, StructuralShape};
+    use structural_shapes::{
+        catalog, degrees, meters, radians, CompositeShape, PolygonShape, StructuralShape,
+        ThinWalledShape,
+    };
 
     #[test]
     fn rod_symmetry() {
         let x = StructuralShape::Rod {
             radius: meters(1.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x(), x.moi_y());
     }
@@ -16,6 +20,7 @@ mod tests {
         let x = StructuralShape::Rod {
             radius: meters(1.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x().value, std::f64::consts::PI / 4.0);
     }
@@ -26,6 +31,7 @@ mod tests {
             outer_radius: meters(1.0),
             thickness: meters(0.01),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x(), x.moi_y());
     }
@@ -36,6 +42,7 @@ mod tests {
             outer_radius: meters(2.0),
             thickness: meters(1.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x().value, std::f64::consts::PI * 15.0 / 4.0);
     }
@@ -46,6 +53,7 @@ mod tests {
             width: meters(2.0),
             height: meters(2.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x(), x.moi_y());
     }
@@ -56,6 +64,7 @@ mod tests {
             width: meters(2.0),
             height: meters(2.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x().value, 16.0 / 12.0);
     }
@@ -65,8 +74,13 @@ mod tests {
         let x = StructuralShape::BoxBeam {
             width: meters(3.0),
             height: meters(3.0),
-            thickness: meters(1.0),
+            flange_thickness: meters(1.0),
+            web_thickness: meters(1.0),
+            overhang: meters(0.0),
+            outer_radius: meters(0.0),
+            inner_radius: meters(0.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x(), x.moi_y());
     }
@@ -76,8 +90,13 @@ mod tests {
         let x = StructuralShape::BoxBeam {
             width: meters(3.0),
             height: meters(3.0),
-            thickness: meters(1.0),
+            flange_thickness: meters(1.0),
+            web_thickness: meters(1.0),
+            overhang: meters(0.0),
+            outer_radius: meters(0.0),
+            inner_radius: meters(0.0),
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x().value, 80.0 / 12.0);
     }
@@ -89,7 +108,10 @@ mod tests {
             height: meters(2.0),
             flange_thickness: meters(1.0),
             web_thickness: meters(1.0),
+            root_radius: meters(0.0),
+            flange_slope: 0.0,
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x(), x.moi_y());
     }
@@ -101,12 +123,16 @@ mod tests {
             height: meters(2.0),
             flange_thickness: meters(1.0),
             web_thickness: meters(1.0),
+            root_radius: meters(0.0),
+            flange_slope: 0.0,
             center_of_gravity: (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         let y = StructuralShape::Rectangle {
             width: meters(2.0),
             height: meters(2.0),
             center_of_gravity:  (meters(0.0), meters(0.0)),
+            rotation: radians(0.0),
         };
         assert_eq!(x.moi_x(), y.moi_x());
         assert_eq!(x.moi_y(), y.moi_y());
@@ -118,10 +144,12 @@ mod tests {
             .add(StructuralShape::Rod {
                 radius: meters(2.0),
                 center_of_gravity: (meters(3.0), meters(0.0)),
+                rotation: radians(0.0),
             })
             .add(StructuralShape::Rod {
                 radius: meters(2.0),
                 center_of_gravity:  (meters(-3.0), meters(0.0)),
+                rotation: radians(0.0),
             });
         println!("moi-x: {}", x.moi_x().value);
         println!("moi-y: {}", x.moi_y().value);
@@ -135,10 +163,12 @@ mod tests {
         x.add(StructuralShape::Rod {
             radius: meters(2.0),
             center_of_gravity:  (meters(3.0), meters(0.0)),
+            rotation: radians(0.0),
         });
         x.add(StructuralShape::Rod {
             radius: meters(2.0),
             center_of_gravity:  (meters(-3.0), meters(0.0)),
+            rotation: radians(0.0),
         });
         println!("moi-x: {}", x.moi_x().value);
         println!("moi-y: {}", x.moi_y().value);
@@ -153,14 +183,1243 @@ mod tests {
                 width: meters(1.0),
                 height: meters(1.0),
                 center_of_gravity:  (meters(2.0), meters(1.5)),
+                rotation: radians(0.0),
             })
             .sub(StructuralShape::Rectangle {
                 width: meters(0.9),
                 height: meters(0.9),
                 center_of_gravity: (meters(2.0), meters(1.5)),
+                rotation: radians(0.0),
             });
         assert_eq!(x.calculate_cog(), (meters(2.0), meters(1.5)));
         x.update_cog();
         assert_eq!(x.calculate_cog(), (meters(0.0), meters(0.0)),);
     }
+
+    #[test]
+    fn rectangle_offset_value() {
+        let x = StructuralShape::new_rectangle(2.0, 1.0).with_cog(3.0, 4.0);
+        assert_eq!(x.moi_x().value, 8.0 / 12.0 + 2.0 * 16.0);
+        assert_eq!(x.moi_y().value, 2.0 / 12.0 + 2.0 * 9.0);
+    }
+
+    #[test]
+    fn product_moi_symmetric() {
+        let x = StructuralShape::new_ibeam(2.0, 1.0, 0.1, 0.1);
+        assert_eq!(x.product_moi().value, 0.0);
+    }
+
+    #[test]
+    fn product_moi_offset() {
+        let x = StructuralShape::new_rectangle(2.0, 1.0).with_cog(3.0, 4.0);
+        assert_eq!(x.product_moi().value, 24.0);
+    }
+
+    #[test]
+    fn composite_product_moi() {
+        let x = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(4.0, 1.0).with_cog(0.5, 2.0))
+            .add(StructuralShape::new_rectangle(1.0, 3.0).with_cog(2.5, 0.5));
+        assert_eq!(x.product_moi().value, 7.75);
+    }
+
+    #[test]
+    fn principal_rectangle() {
+        let x = StructuralShape::new_rectangle(1.0, 2.0).with_cog(1.0, 1.0);
+        let (i1, i2) = x.principal_moi();
+        assert!((x.principal_angle().value - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((i1.value - 8.0 / 12.0).abs() < 1e-12);
+        assert!((i2.value - 2.0 / 12.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn principal_composite_angle() {
+        let x = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(4.0, 1.0).with_cog(0.5, 2.0))
+            .add(StructuralShape::new_rectangle(1.0, 3.0).with_cog(2.5, 0.5));
+        let (cx, cy) = x.calculate_cog();
+        let ix = x.moi_x() - x.area() * cy * cy;
+        let iy = x.moi_y() - x.area() * cx * cx;
+        let ixy = x.product_moi() - x.area() * cx * cy;
+        let (i1, i2) = x.principal_moi();
+        assert!((x.principal_angle().value - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        assert!(((i1 + i2) - (ix + iy)).value.abs() < 1e-12);
+        assert!((i1 - i2 - 2.0 * ixy.abs()).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn rectangle_section_modulus() {
+        let x = StructuralShape::new_rectangle(2.0, 3.0).with_cog(5.0, -1.0);
+        let (top, bottom) = x.section_modulus_x();
+        let (left, right) = x.section_modulus_y();
+        assert!((top.value - 2.0).abs() < 1e-12);
+        assert!((bottom.value - 2.0).abs() < 1e-12);
+        assert!((left.value - 3.0).abs() < 1e-12);
+        assert!((right.value - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn composite_section_modulus() {
+        let x = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(1.0, 4.0).with_cog(0.0, 3.5))
+            .add(StructuralShape::new_rectangle(3.0, 1.0).with_cog(0.0, 1.5));
+        let ((_, y_min), (_, y_max)) = x.bounding_box();
+        assert_eq!((y_min.value, y_max.value), (0.0, 4.0));
+        let cy = 18.5 / 7.0;
+        let ix = 4.0 / 12.0 + 4.0 * 3.5 * 3.5 + 27.0 / 12.0 + 3.0 * 1.5 * 1.5 - 7.0 * cy * cy;
+        let (top, bottom) = x.section_modulus_x();
+        assert!((top.value - ix / (4.0 - cy)).abs() < 1e-12);
+        assert!((bottom.value - ix / cy).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rectangle_plastic_modulus() {
+        let x = StructuralShape::new_rectangle(2.0, 3.0).with_cog(1.0, 1.0);
+        assert!((x.plastic_modulus_x().value - 3.0).abs() < 1e-9);
+        assert!((x.plastic_modulus_y().value - 4.5).abs() < 1e-9);
+        assert!((x.shape_factor_x() - 1.5).abs() < 1e-9);
+        let (pna_x, pna_y) = x.plastic_neutral_axis();
+        assert!((pna_x.value - 1.0).abs() < 1e-9);
+        assert!((pna_y.value - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pipe_plastic_modulus() {
+        let x = StructuralShape::new_pipe(2.0, 1.0);
+        assert!((x.plastic_modulus_x().value - 4.0 * 7.0 / 3.0).abs() < 1e-9);
+        assert!((x.plastic_modulus_y().value - 4.0 * 7.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rod_shape_factor() {
+        let x = StructuralShape::new_rod(1.0);
+        assert!((x.shape_factor_x() - 16.0 / (3.0 * std::f64::consts::PI)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ibeam_plastic_modulus() {
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+        let zx = 0.25 * 0.05 * 0.45 + 0.025 * 0.4 * 0.4 / 4.0;
+        let zy = 2.0 * 0.05 * 0.25 * 0.25 / 4.0 + 0.4 * 0.025 * 0.025 / 4.0;
+        assert!((x.plastic_modulus_x().value - zx).abs() < 1e-9);
+        assert!((x.plastic_modulus_y().value - zy).abs() < 1e-9);
+    }
+
+    #[test]
+    fn composite_plastic_neutral_axis() {
+        let x = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(1.0, 4.0).with_cog(0.0, 3.5))
+            .add(StructuralShape::new_rectangle(3.0, 1.0).with_cog(0.0, 1.5));
+        assert!((x.plastic_neutral_axis().1.value - 3.125).abs() < 1e-9);
+        assert!((x.plastic_modulus_x().value - 6.4375).abs() < 1e-9);
+    }
+
+    #[test]
+    fn composite_without_added_shapes() {
+        // Nothing to bound, so the box is empty and the moduli have no area to work with
+        let x = CompositeShape::new();
+        assert_eq!(x.bounding_box(), ((meters(0.0), meters(0.0)), (meters(0.0), meters(0.0))));
+        assert_eq!(x.plastic_modulus_x().value, 0.0);
+        assert_eq!(x.plastic_neutral_axis(), (meters(0.0), meters(0.0)));
+        assert!(x.section_modulus_y().0.value.is_nan());
+        assert!(x.shape_factor_x().is_nan());
+
+        // Subtracted shapes alone do not bound the composite either
+        let x = CompositeShape::new().sub(StructuralShape::new_rod(0.1).with_cog(1.0, 1.0));
+        assert_eq!(x.bounding_box(), CompositeShape::new().bounding_box());
+        assert!(x.plastic_modulus_y().value.is_finite());
+    }
+
+    #[test]
+    fn circular_torsion_constant() {
+        let x = StructuralShape::new_pipe(2.0, 1.0);
+        assert_eq!(x.torsion_constant(), x.polar_moi());
+        let y = StructuralShape::new_rod(1.0);
+        assert_eq!(y.torsion_constant().value, std::f64::consts::PI / 2.0);
+    }
+
+    #[test]
+    fn ibeam_torsion_constant() {
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+        let j = (2.0 * 0.25 * 0.05f64.powi(3) + 0.45 * 0.025f64.powi(3)) / 3.0;
+        assert!((x.torsion_constant().value - j).abs() < 1e-12);
+        assert!(x.torsion_constant() < x.polar_moi() / 10.0);
+    }
+
+    #[test]
+    fn boxbeam_torsion_constant() {
+        let x = StructuralShape::new_boxbeam(3.0, 2.0, 0.1);
+        let j = 0.001 * 9.6 / 3.0 + 4.0 * (1.9f64 * 2.9).powi(2) * 0.1 / 9.6;
+        assert!((x.torsion_constant().value - j).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rectangle_torsion_constant() {
+        let x = StructuralShape::new_rectangle(1.0, 1.0);
+        assert!((x.torsion_constant().value - 0.1406).abs() < 1e-3);
+    }
+
+    #[test]
+    fn ibeam_warping_constant() {
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+        let flange_moi = 0.05 * 0.25f64.powi(3) / 12.0;
+        let cw = 2.0 * flange_moi * 0.45 * 0.45 / 4.0;
+        assert!((x.warping_constant().value - cw).abs() < 1e-15);
+        assert_eq!(x.monosymmetry_parameter().value, 0.0);
+    }
+
+    #[test]
+    fn boxbeam_warping_constant() {
+        let x = StructuralShape::new_boxbeam(2.0, 2.0, 0.1);
+        assert_eq!(x.warping_constant().value, 0.0);
+        let y = StructuralShape::new_boxbeam(3.0, 2.0, 0.1);
+        assert!(y.warping_constant().value > 0.0);
+    }
+
+    #[test]
+    fn symmetric_shear_center() {
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(1.0, 2.0);
+        assert_eq!(x.shear_center(), (meters(1.0), meters(2.0)));
+    }
+
+    #[test]
+    fn rectangle_radius_of_gyration() {
+        let x = StructuralShape::new_rectangle(2.0, 1.0).with_cog(3.0, 3.0);
+        assert!((x.radius_of_gyration_x().value - 2.0 / 12f64.sqrt()).abs() < 1e-12);
+        assert!((x.radius_of_gyration_y().value - 1.0 / 12f64.sqrt()).abs() < 1e-12);
+        assert!((x.radius_of_gyration_min().value - 1.0 / 12f64.sqrt()).abs() < 1e-12);
+        assert!((x.slenderness(meters(1.0)) - 12f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn composite_radius_of_gyration() {
+        let x = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(4.0, 1.0).with_cog(0.5, 2.0))
+            .add(StructuralShape::new_rectangle(1.0, 3.0).with_cog(2.5, 0.5));
+        let r_min = (x.principal_moi().1 / x.area()).value.sqrt();
+        assert!((x.radius_of_gyration_min().value - r_min).abs() < 1e-12);
+        assert!(x.radius_of_gyration_min() < x.radius_of_gyration_x());
+        assert!((x.slenderness(meters(2.0)) - 2.0 / r_min).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ibeam_closed_form_matches_composite() {
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(0.3, -0.2);
+        let y = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.05, 0.25).with_cog(0.3, 0.025))
+            .add(StructuralShape::new_rectangle(0.4, 0.025).with_cog(0.3, -0.2))
+            .add(StructuralShape::new_rectangle(0.05, 0.25).with_cog(0.3, -0.425));
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!((x.centroidal_moi_x() - y.centroidal_moi_x()).value.abs() < 1e-12);
+        assert!((x.centroidal_moi_y() - y.centroidal_moi_y()).value.abs() < 1e-12);
+        assert!(x.centroidal_moi_x() > x.centroidal_moi_y());
+    }
+
+    #[test]
+    fn centroidal_and_reference_moi() {
+        let x = StructuralShape::new_boxbeam(3.0, 2.0, 0.1).with_cog(1.0, 2.0);
+        let area = x.area();
+        assert!((x.moi_x() - x.centroidal_moi_x() - area * meters(2.0) * meters(2.0)).value.abs() < 1e-12);
+        assert!((x.moi_y() - x.centroidal_moi_y() - area * meters(1.0) * meters(1.0)).value.abs() < 1e-12);
+        assert_eq!(x.centroidal_product_moi().value, 0.0);
+    }
+
+    #[test]
+    fn moi_about_axes() {
+        let x = StructuralShape::new_rectangle(2.0, 1.0).with_cog(1.0, 2.0);
+        let cog = (meters(1.0), meters(2.0));
+        let origin = (meters(0.0), meters(0.0));
+        let horizontal = x.moi_about(cog, degrees(0.0));
+        let vertical = x.moi_about(cog, degrees(90.0));
+        assert!((horizontal - x.centroidal_moi_x()).value.abs() < 1e-12);
+        assert!((vertical - x.centroidal_moi_y()).value.abs() < 1e-12);
+        assert!((x.moi_about(origin, degrees(0.0)) - x.moi_x()).value.abs() < 1e-12);
+        let diagonal = x.moi_about(cog, degrees(45.0));
+        assert!((diagonal - (horizontal + vertical) / 2.0).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotated_rectangle_quarter_turn() {
+        let x = StructuralShape::new_rectangle(1.0, 2.0)
+            .with_cog(1.0, 2.0)
+            .with_rotation(std::f64::consts::FRAC_PI_2);
+        let y = StructuralShape::new_rectangle(2.0, 1.0).with_cog(1.0, 2.0);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!((x.product_moi() - y.product_moi()).value.abs() < 1e-12);
+        let (lower, upper) = x.bounding_box();
+        assert!((lower.0.value - 0.5).abs() < 1e-12 && (lower.1.value - 1.0).abs() < 1e-12);
+        assert!((upper.0.value - 1.5).abs() < 1e-12 && (upper.1.value - 3.0).abs() < 1e-12);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-9);
+    }
+
+    #[test]
+    fn rotated_rectangle_mohr() {
+        let angle = degrees(30.0);
+        let x = StructuralShape::new_rectangle(1.0, 2.0).with_rotation(angle.value);
+        let (ix, iy) = (2.0 / 12.0, 8.0 / 12.0);
+        let (sin, cos) = angle.value.sin_cos();
+        assert!((x.centroidal_moi_x().value - (ix * cos * cos + iy * sin * sin)).abs() < 1e-12);
+        assert!((x.centroidal_moi_y().value - (ix * sin * sin + iy * cos * cos)).abs() < 1e-12);
+        assert!((x.centroidal_product_moi().value - (iy - ix) * sin * cos).abs() < 1e-12);
+        assert!((x.principal_angle().value - (angle.value - std::f64::consts::FRAC_PI_2)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotated_square_plastic_modulus() {
+        let x = StructuralShape::new_rectangle(1.0, 1.0).with_rotation(std::f64::consts::FRAC_PI_4);
+        assert!((x.plastic_modulus_x().value - 1.0 / (3.0 * 2f64.sqrt())).abs() < 1e-9);
+        let (top, bottom) = x.section_modulus_x();
+        assert!((top.value - 2f64.sqrt() / 12.0).abs() < 1e-12);
+        assert!((bottom.value - 2f64.sqrt() / 12.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotated_ibeam_properties() {
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05)
+            .with_cog(1.0, -1.0)
+            .with_rotation(std::f64::consts::FRAC_PI_2);
+        let y = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05);
+        assert!((x.centroidal_moi_x() - y.centroidal_moi_y()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_y()).value.abs() < 1e-9);
+        assert!((x.plastic_modulus_y() - y.plastic_modulus_x()).value.abs() < 1e-9);
+    }
+
+    #[test]
+    fn composite_with_inclined_plate() {
+        let x = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.1, 2.0))
+            .add(StructuralShape::new_rectangle(0.1, 2.0).with_rotation(std::f64::consts::FRAC_PI_2));
+        let y = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.1, 2.0).with_rotation(std::f64::consts::FRAC_PI_4))
+            .add(StructuralShape::new_rectangle(0.1, 2.0).with_rotation(-std::f64::consts::FRAC_PI_4));
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!(y.product_moi().value.abs() < 1e-12);
+        let z = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.1, 2.0).with_rotation(std::f64::consts::FRAC_PI_4));
+        assert!((z.product_moi().value - (0.8 - 0.002) / 24.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn channel_matches_composite() {
+        let x = StructuralShape::new_channel(0.3, 0.1, 0.01, 0.015).with_cog(1.0, 2.0);
+        let (x_bar, y_bar) = x.centroid_offset();
+        assert!((y_bar.value - 0.15).abs() < 1e-12);
+        let web_x = 1.0 - x_bar.value + 0.005;
+        let flange_x = 1.0 - x_bar.value + 0.055;
+        let y = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.3, 0.01).with_cog(web_x, 2.0))
+            .add(StructuralShape::new_rectangle(0.015, 0.09).with_cog(flange_x, 2.1425))
+            .add(StructuralShape::new_rectangle(0.015, 0.09).with_cog(flange_x, 1.8575));
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((y.calculate_cog().0.value - 1.0).abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_y() - y.plastic_modulus_y()).value.abs() < 1e-9);
+        assert!((x.section_modulus_y().0 - y.section_modulus_y().0).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn channel_shear_center() {
+        let x = StructuralShape::new_channel(0.3, 0.1, 0.01, 0.015).with_cog(1.0, 2.0);
+        let (b, h, tf, tw) = (0.095, 0.285, 0.015, 0.01);
+        let e = 3.0 * b * b * tf / (6.0 * b * tf + h * tw);
+        let web_center = 1.0 - x.centroid_offset().0.value + 0.005;
+        let (sx, sy) = x.shear_center();
+        assert!((sx.value - (web_center - e)).abs() < 1e-12);
+        assert!((sy.value - 2.0).abs() < 1e-12);
+        let y = x.clone().with_rotation(std::f64::consts::PI);
+        assert!((y.shear_center().0.value - (2.0 - sx.value)).abs() < 1e-12);
+        assert!(x.warping_constant().value > 0.0);
+    }
+
+    #[test]
+    fn angle_matches_composite() {
+        let x = StructuralShape::new_angle(0.15, 0.1, 0.01).with_cog(1.0, 2.0);
+        let (x_bar, y_bar) = x.centroid_offset();
+        let (heel_x, heel_y) = (1.0 - x_bar.value, 2.0 - y_bar.value);
+        let y = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.15, 0.01).with_cog(heel_x + 0.005, heel_y + 0.075))
+            .add(StructuralShape::new_rectangle(0.01, 0.09).with_cog(heel_x + 0.055, heel_y + 0.005));
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((y.calculate_cog().0.value - 1.0).abs() < 1e-12);
+        assert!((y.calculate_cog().1.value - 2.0).abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!((x.product_moi() - y.product_moi()).value.abs() < 1e-12);
+        assert!(x.centroidal_product_moi().value < 0.0);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-9);
+        let ((x_min, y_min), (x_max, y_max)) = x.bounding_box();
+        assert!((x_min.value - heel_x).abs() < 1e-12 && (y_min.value - heel_y).abs() < 1e-12);
+        assert!((x_max.value - heel_x - 0.1).abs() < 1e-12 && (y_max.value - heel_y - 0.15).abs() < 1e-12);
+    }
+
+    #[test]
+    fn angle_principal_axes() {
+        // An L4x4x1/2 without fillets
+        let x = StructuralShape::new_angle(4.0, 4.0, 0.5);
+        assert!((x.principal_angle().value - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        let (i1, i2) = x.principal_moi();
+        let ixy = x.centroidal_product_moi().abs();
+        assert!((i1 - x.centroidal_moi_x() - ixy).value.abs() < 1e-12);
+        assert!((i2 - x.centroidal_moi_x() + ixy).value.abs() < 1e-12);
+        assert!((x.principal_radius_of_gyration().1 - x.radius_of_gyration_min()).value.abs() < 1e-12);
+        assert!((x.radius_of_gyration_min().value - 0.782).abs() < 1e-3);
+        assert!(x.monosymmetry_parameter().value.abs() < 1e-12);
+        let (sx, sy) = x.shear_center();
+        let (x_bar, y_bar) = x.centroid_offset();
+        assert!((sx.value - 0.25 + x_bar.value).abs() < 1e-12);
+        assert!((sy.value - 0.25 + y_bar.value).abs() < 1e-12);
+
+        // Swapping the legs of an unequal angle mirrors it about the major principal axis
+        let y = StructuralShape::new_angle(6.0, 4.0, 0.5);
+        let z = StructuralShape::new_angle(4.0, 6.0, 0.5);
+        assert!((y.principal_angle().value.tan() - 0.441).abs() < 1e-3);
+        assert!((y.monosymmetry_parameter() + z.monosymmetry_parameter()).value.abs() < 1e-12);
+        assert!(y.monosymmetry_parameter().value > 0.0);
+        // AISC lists βw = 3.14 in. for an L6x4x1/2, which has small fillets
+        assert!((3.12..3.16).contains(&y.monosymmetry_parameter().value));
+        assert!((y.torsion_constant().value - 9.5 * 0.125 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tee_cut_from_ibeam() {
+        // A WT6x13 cut from a W12x26, without fillets
+        let x = StructuralShape::new_ibeam(12.2, 6.49, 0.23, 0.38).cut_tee().unwrap().with_cog(1.0, 2.0);
+        assert!(StructuralShape::new_rod(1.0).cut_tee().is_none());
+        let (_, y_bar) = x.centroid_offset();
+        assert!((6.1 - y_bar.value - 1.25).abs() < 1e-2);
+        let bottom = 2.0 - y_bar.value;
+        let y = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(0.38, 6.49).with_cog(1.0, bottom + 5.91))
+            .add(StructuralShape::new_rectangle(5.72, 0.23).with_cog(1.0, bottom + 2.86));
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((y.calculate_cog().1.value - 2.0).abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-9);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-9);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-9);
+        assert!((x.section_modulus_x().1 - y.section_modulus_x().1).value.abs() < 1e-9);
+        assert!((x.shear_center().1.value - bottom - 5.91).abs() < 1e-12);
+        assert!(x.monosymmetry_parameter().value > 0.0);
+        let z = x.clone().with_rotation(std::f64::consts::PI);
+        assert!((x.monosymmetry_parameter() - z.monosymmetry_parameter()).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn double_tee_matches_composite() {
+        let x = StructuralShape::new_double_tee(24.0, 96.0, 2.0, 4.75, 48.0);
+        let (x_bar, y_bar) = x.centroid_offset();
+        assert!((x_bar.value - 48.0).abs() < 1e-12);
+        assert!((x.area().value - 401.0).abs() < 1e-12);
+        let bottom = -y_bar.value;
+        let y = CompositeShape::new()
+            .add(StructuralShape::new_rectangle(2.0, 96.0).with_cog(0.0, bottom + 23.0))
+            .add(StructuralShape::new_rectangle(22.0, 4.75).with_cog(-24.0, bottom + 11.0))
+            .add(StructuralShape::new_rectangle(22.0, 4.75).with_cog(24.0, bottom + 11.0));
+        assert!(y.calculate_cog().1.value.abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-9);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-9);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-6);
+        assert!(((x.bounding_box().0).1.value - bottom).abs() < 1e-12);
+        // The shear center sits above the flange, away from the stems
+        assert!(x.shear_center().1.value > bottom + 24.0);
+        assert!(x.warping_constant().value > 0.0);
+    }
+
+    #[test]
+    fn lipped_channel_without_lips_matches_channel() {
+        // The same channel, measured to the centerline, but with bends of zero inner radius that
+        // round off the outer corners
+        let x = StructuralShape::new_lipped_channel(0.29, 0.095, 0.0, 0.01, 0.0).with_cog(1.0, 2.0);
+        let y = StructuralShape::new_channel(0.3, 0.1, 0.01, 0.01).with_cog(1.0, 2.0);
+        let corners = 2.0 * 0.01 * 0.01 * (1.0 - std::f64::consts::FRAC_PI_4);
+        assert!(((y.area() - x.area()).value - corners).abs() < 1e-7);
+        assert!(((x.moi_x() - y.moi_x()) / y.moi_x()).value.abs() < 1e-2);
+        assert!(((x.centroidal_moi_y() - y.centroidal_moi_y()) / y.centroidal_moi_y()).value.abs() < 1e-2);
+        assert!(((x.warping_constant() - y.warping_constant()) / y.warping_constant()).value.abs() < 2e-2);
+        assert!((x.shear_center().0 - y.shear_center().0).value.abs() < 1e-3);
+        assert!((x.shear_center().1.value - 2.0).abs() < 1e-12);
+        let ((x_min, y_min), (x_max, y_max)) = x.bounding_box();
+        assert!((x_max - x_min - meters(0.1)).value.abs() < 1e-12);
+        assert!((y_max - y_min - meters(0.3)).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn cold_formed_shapes() {
+        let z = StructuralShape::new_lipped_z(0.2, 0.07, 0.02, 0.002, 0.003).with_cog(1.0, 2.0);
+        let (sx, sy) = z.shear_center();
+        assert!((sx.value - 1.0).abs() < 1e-12 && (sy.value - 2.0).abs() < 1e-12);
+        assert!(z.centroidal_product_moi().value > 0.0);
+        let flipped = z.clone().with_rotation(std::f64::consts::PI);
+        assert!((z.centroidal_product_moi() - flipped.centroidal_product_moi()).value.abs() < 1e-15);
+        assert!(z.principal_moi().1 < z.centroidal_moi_y());
+
+        // A lipped channel and a sigma with the same outline have the same torsion constant
+        let c = StructuralShape::new_lipped_channel(0.2, 0.07, 0.02, 0.002, 0.003);
+        let s = StructuralShape::new_sigma(0.2, 0.07, 0.02, 0.07, 0.0, 0.06, 0.002, 0.003);
+        assert!((c.torsion_constant() - s.torsion_constant()).value.abs() < 1e-18);
+        assert!((c.moi_x() - s.moi_x()).value.abs() < 1e-15);
+        assert!(c.shear_center().1.value.abs() < 1e-12);
+        let sigma = StructuralShape::new_sigma(0.2, 0.062, 0.02, 0.045, 0.015, 0.06, 0.002, 0.003);
+        assert!(sigma.warping_constant() < c.warping_constant());
+
+        // The shear center of a hat lies on its axis of symmetry, above the crown
+        let h = StructuralShape::new_hat(0.05, 0.05, 0.03, 0.001, 0.002);
+        let (hx, hy) = h.shear_center();
+        assert!(hx.value.abs() < 1e-12);
+        assert!(hy > (h.bounding_box().1).1);
+        assert!(h.monosymmetry_parameter().value != 0.0);
+        assert!((h.plastic_neutral_axis().0).value.abs() < 1e-9);
+    }
+
+    #[test]
+    fn polygon_matches_analytic_shapes() {
+        let ibeam = PolygonShape::new(
+            &[
+                (0.0, 0.0), (0.25, 0.0), (0.25, 0.05), (0.1375, 0.05), (0.1375, 0.45), (0.25, 0.45),
+                (0.25, 0.5), (0.0, 0.5), (0.0, 0.45), (0.1125, 0.45), (0.1125, 0.05), (0.0, 0.05),
+            ],
+            &[],
+        );
+        let x = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(0.125, 0.25);
+        assert!((ibeam.area() - x.area()).value.abs() < 1e-12);
+        assert!((ibeam.moi_x() - x.moi_x()).value.abs() < 1e-12);
+        assert!((ibeam.moi_y() - x.moi_y()).value.abs() < 1e-12);
+        assert!((ibeam.product_moi() - x.product_moi()).value.abs() < 1e-12);
+        assert!((ibeam.plastic_modulus_x() - x.plastic_modulus_x()).value.abs() < 1e-9);
+        assert!((ibeam.section_modulus_y().0 - x.section_modulus_y().0).value.abs() < 1e-12);
+
+        // Holes may be listed in either direction
+        let outer = [(1.0, 2.0), (1.2, 2.0), (1.2, 2.3), (1.0, 2.3)];
+        let hole = vec![(1.01, 2.01), (1.19, 2.01), (1.19, 2.29), (1.01, 2.29)];
+        let reversed = hole.iter().rev().cloned().collect();
+        let boxbeam = StructuralShape::new_boxbeam(0.3, 0.2, 0.01).with_cog(1.1, 2.15);
+        for polygon in [
+            PolygonShape::new(&outer, &[hole]),
+            PolygonShape::new(&outer, &[reversed]),
+        ] {
+            assert!((polygon.area() - boxbeam.area()).value.abs() < 1e-12);
+            assert!((polygon.moi_x() - boxbeam.moi_x()).value.abs() < 1e-12);
+            assert!((polygon.centroidal_moi_y() - boxbeam.centroidal_moi_y()).value.abs() < 1e-12);
+            assert!((polygon.plastic_modulus_y() - boxbeam.plastic_modulus_y()).value.abs() < 1e-9);
+        }
+
+        let angle = PolygonShape::new(
+            &[(0.0, 0.0), (0.1, 0.0), (0.1, 0.01), (0.01, 0.01), (0.01, 0.15), (0.0, 0.15)],
+            &[],
+        );
+        let x = StructuralShape::new_angle(0.15, 0.1, 0.01);
+        assert!((angle.centroidal_product_moi() - x.centroidal_product_moi()).value.abs() < 1e-12);
+        assert!((angle.principal_angle() - x.principal_angle()).value.abs() < 1e-9);
+        assert!((angle.centroid_offset().0 - x.centroid_offset().0).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn polygon_in_composite() {
+        let triangle = PolygonShape::new(&[(0.0, 0.0), (0.3, 0.0), (0.0, 0.3)], &[]);
+        let (cx, cy) = triangle.centroid_offset();
+        assert!((cx.value - 0.1).abs() < 1e-12 && (cy.value - 0.1).abs() < 1e-12);
+        assert!((triangle.centroidal_moi_x().value - 0.3 * 0.027 / 36.0).abs() < 1e-12);
+        assert!((triangle.centroidal_product_moi().value + 0.0081 / 72.0).abs() < 1e-12);
+
+        // A square made of two triangles
+        let other = PolygonShape::new(&[(0.3, 0.0), (0.3, 0.3), (0.0, 0.3)], &[]);
+        let x = CompositeShape::new().add_polygon(triangle.clone()).add_polygon(other);
+        let y = StructuralShape::new_rectangle(0.3, 0.3).with_cog(0.15, 0.15);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!(x.centroidal_product_moi().value.abs() < 1e-12);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-9);
+
+        // Rotating the shape matches rotating its coordinates
+        let rotated = PolygonShape::new(&[(0.0, 0.0), (0.0, 0.3), (-0.3, 0.0)], &[]);
+        let turned = triangle.clone().with_rotation(std::f64::consts::FRAC_PI_2);
+        assert!((rotated.centroidal_moi_x() - turned.centroidal_moi_x()).value.abs() < 1e-12);
+        assert!((rotated.centroidal_product_moi() - turned.centroidal_product_moi()).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn polygon_rejects_degenerate_rings() {
+        let square = [(0.0, 0.0), (0.3, 0.0), (0.3, 0.3), (0.0, 0.3)];
+        let rejected = |outer: Vec<(f64, f64)>, holes: Vec<Vec<(f64, f64)>>| {
+            std::panic::catch_unwind(|| PolygonShape::new(&outer, &holes)).is_err()
+        };
+        // Too few vertices, in the boundary or in a hole
+        assert!(rejected(vec![(0.0, 0.0), (0.3, 0.0)], vec![]));
+        assert!(rejected(square.to_vec(), vec![vec![(0.1, 0.1), (0.2, 0.1)]]));
+        // A boundary along a straight line, and one that crosses itself so that its halves cancel
+        assert!(rejected(vec![(0.0, 0.0), (0.1, 0.1), (0.3, 0.3)], vec![]));
+        assert!(rejected(vec![(0.0, 0.0), (0.3, 0.3), (0.3, 0.0), (0.0, 0.3)], vec![]));
+        // A hole as large as the boundary
+        assert!(rejected(square.to_vec(), vec![square.to_vec()]));
+        assert!(!rejected(square.to_vec(), vec![]));
+    }
+
+    #[test]
+    fn thin_walled_open() {
+        // An I-section along the centerlines of its flanges and web
+        let nodes = [(-0.1, 0.0), (0.0, 0.0), (0.1, 0.0), (-0.1, 0.3), (0.0, 0.3), (0.1, 0.3)];
+        let walls = [(0, 1, 0.015), (1, 2, 0.015), (3, 4, 0.015), (4, 5, 0.015), (1, 4, 0.01)];
+        let shape = ThinWalledShape::new(&nodes, &walls);
+        let x = StructuralShape::new_ibeam(0.315, 0.2, 0.01, 0.015).with_cog(0.0, 0.15);
+        assert!((shape.area().value - (2.0 * 0.2 * 0.015 + 0.3 * 0.01)).abs() < 1e-12);
+        assert!((shape.centroid_offset().1.value - 0.1575).abs() < 1e-12);
+        assert!((shape.torsion_constant() - x.torsion_constant()).value.abs() < 1e-15);
+        assert!((shape.warping_constant() - x.warping_constant()).value.abs() < 1e-15);
+        assert!((shape.shear_center().1.value - 0.15).abs() < 1e-12);
+        let omega = shape.sectorial_coordinates();
+        assert!((omega[0].value.abs() - 0.1 * 0.3 / 2.0).abs() < 1e-12);
+        assert!(omega[1].value.abs() < 1e-12);
+
+        // A channel from a polyline, against the closed-form shear center and warping constant
+        let points = [(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+        let shape = ThinWalledShape::new_polyline(&points, &[0.012, 0.008, 0.012]);
+        let x = StructuralShape::new_channel(0.312, 0.104, 0.008, 0.012);
+        let eccentricity = 3.0 * 0.01 * 0.012 / (6.0 * 0.1 * 0.012 + 0.3 * 0.008);
+        assert!((shape.shear_center().0.value + eccentricity).abs() < 1e-12);
+        assert!((shape.shear_center().1.value - 0.15).abs() < 1e-12);
+        assert!((shape.warping_constant() - x.warping_constant()).value.abs() < 1e-15);
+
+        // Rotation moves the shear center with the shape but leaves the constants alone
+        let turned = shape.clone().with_rotation(std::f64::consts::FRAC_PI_2);
+        assert!((turned.torsion_constant() - shape.torsion_constant()).value.abs() < 1e-15);
+        assert!((turned.centroidal_moi_x() - shape.centroidal_moi_y()).value.abs() < 1e-12);
+        let (cx, cy) = CompositeShape::new().add_thin_walled(shape.clone()).calculate_cog();
+        assert!((turned.shear_center().1 - cy - shape.shear_center().0 + cx).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn thin_walled_closed() {
+        // A single cell matches the box beam
+        let points = [(0.0, 0.0), (0.2, 0.0), (0.2, 0.3), (0.0, 0.3), (0.0, 0.0)];
+        let shape = ThinWalledShape::new_polyline(&points, &[0.01; 4]);
+        let x = StructuralShape::new_boxbeam(0.31, 0.21, 0.01);
+        let (perimeter, enclosed) = (1.0, 0.06);
+        let j = 1e-6 * perimeter / 3.0 + 4.0 * enclosed * enclosed * 0.01 / perimeter;
+        assert!((shape.torsion_constant().value - j).abs() < 1e-15);
+        assert!((shape.torsion_constant() - x.torsion_constant()).value.abs() < 1e-15);
+        assert!((shape.warping_constant() - x.warping_constant()).value.abs() < 1e-15);
+        let (sx, sy) = shape.shear_center();
+        assert!((sx.value - 0.1).abs() < 1e-12 && (sy.value - 0.15).abs() < 1e-12);
+
+        // A symmetric middle web carries no circulating shear flow, so it only adds its own term
+        let nodes = [(0.0, 0.0), (0.2, 0.0), (0.4, 0.0), (0.4, 0.3), (0.2, 0.3), (0.0, 0.3)];
+        let mut walls = vec![(0, 1, 0.01), (1, 2, 0.01), (2, 3, 0.01), (3, 4, 0.01), (4, 5, 0.01), (5, 0, 0.01)];
+        let single = ThinWalledShape::new(&nodes, &walls);
+        walls.push((1, 4, 0.006));
+        let double = ThinWalledShape::new(&nodes, &walls);
+        let web = 0.3 * 0.006 * 0.006 * 0.006 / 3.0;
+        assert!((double.torsion_constant() - single.torsion_constant()).value - web < 1e-15);
+        assert!((double.torsion_constant() - single.torsion_constant()).value - web > -1e-15);
+        assert!((double.area() - single.area()).value - 0.3 * 0.006 < 1e-12);
+
+        // A thicker side wall stiffens the section and draws the shear center towards it
+        let walls = [(0, 1, 0.01), (1, 2, 0.01), (2, 3, 0.02), (3, 4, 0.01), (4, 5, 0.01), (5, 0, 0.01), (1, 4, 0.01)];
+        let shape = ThinWalledShape::new(&nodes, &walls);
+        assert!(shape.torsion_constant() > single.torsion_constant());
+        assert!(shape.shear_center().0.value > 0.2);
+    }
+
+    #[test]
+    fn thin_walled_rejects_invalid_walls() {
+        let nodes = [(0.0, 0.0), (0.2, 0.0), (0.2, 0.3)];
+        let rejected = |walls: Vec<(usize, usize, f64)>| {
+            std::panic::catch_unwind(|| ThinWalledShape::new(&nodes, &walls)).is_err()
+        };
+        // No walls, a wall to a missing node, and walls of no length or thickness
+        assert!(rejected(vec![]));
+        assert!(rejected(vec![(0, 1, 0.01), (1, 3, 0.01)]));
+        assert!(rejected(vec![(0, 1, 0.01), (1, 1, 0.01)]));
+        assert!(rejected(vec![(0, 1, 0.01), (1, 2, 0.0)]));
+        assert!(rejected(vec![(0, 1, 0.01), (1, 2, -0.01)]));
+        assert!(!rejected(vec![(0, 1, 0.01), (1, 2, 0.01)]));
+
+        // Two nodes in the same place
+        let doubled = [(0.0, 0.0), (0.2, 0.0), (0.2, 0.0)];
+        let walls = [(0, 1, 0.01), (1, 2, 0.01)];
+        assert!(std::panic::catch_unwind(|| ThinWalledShape::new(&doubled, &walls)).is_err());
+
+        // A polyline needs one thickness for each segment, whether it is open or closed
+        let rejected = |points: Vec<(f64, f64)>, thicknesses: Vec<f64>| {
+            std::panic::catch_unwind(|| ThinWalledShape::new_polyline(&points, &thicknesses))
+                .is_err()
+        };
+        let open = vec![(0.1, 0.0), (0.0, 0.0), (0.0, 0.3), (0.1, 0.3)];
+        let closed = vec![(0.0, 0.0), (0.2, 0.0), (0.2, 0.3), (0.0, 0.3), (0.0, 0.0)];
+        assert!(rejected(open.clone(), vec![0.01; 2]));
+        assert!(rejected(open.clone(), vec![0.01; 4]));
+        assert!(rejected(closed.clone(), vec![0.01; 3]));
+        assert!(rejected(closed.clone(), vec![0.01; 5]));
+        assert!(rejected(vec![], vec![]));
+        assert!(rejected(vec![(0.0, 0.0)], vec![]));
+        assert!(!rejected(open, vec![0.01; 3]));
+        assert!(!rejected(closed, vec![0.01; 4]));
+    }
+
+    #[test]
+    fn ellipse() {
+        // A circular ellipse is a rod
+        let x = StructuralShape::new_ellipse(0.2, 0.2).with_cog(0.3, -0.1);
+        let y = StructuralShape::new_rod(0.1).with_cog(0.3, -0.1);
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.torsion_constant() - y.torsion_constant()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_y() - y.plastic_modulus_y()).value.abs() < 1e-12);
+
+        let x = StructuralShape::new_ellipse(0.3, 0.1);
+        let (a, b) = (0.05, 0.15);
+        assert!((x.area().value - std::f64::consts::PI * a * b).abs() < 1e-12);
+        assert!((x.moi_x().value - std::f64::consts::PI * a * b * b * b / 4.0).abs() < 1e-12);
+        assert!((x.moi_y().value - std::f64::consts::PI * a * a * a * b / 4.0).abs() < 1e-12);
+        assert!((x.plastic_modulus_x().value - 4.0 * a * b * b / 3.0).abs() < 1e-12);
+        assert!((x.section_modulus_x().0.value - std::f64::consts::PI * a * b * b / 4.0).abs() < 1e-12);
+
+        // Turning an ellipse on its side swaps its axes
+        let turned = x.clone().with_rotation(std::f64::consts::FRAC_PI_2).with_cog(1.0, 2.0);
+        let y = StructuralShape::new_ellipse(0.1, 0.3).with_cog(1.0, 2.0);
+        assert!((turned.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((turned.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        assert!((turned.bounding_box().0 .0 - y.bounding_box().0 .0).value.abs() < 1e-12);
+        assert!((turned.bounding_box().1 .1 - y.bounding_box().1 .1).value.abs() < 1e-12);
+
+        // Half way round, the plastic neutral axis still passes through the center
+        let tilted = x.clone().with_rotation(std::f64::consts::FRAC_PI_4).with_cog(0.0, 0.5);
+        assert!((tilted.plastic_neutral_axis().1.value - 0.5).abs() < 1e-9);
+        let extent = (0.5 * (a * a + b * b)).sqrt();
+        assert!((tilted.bounding_box().1 .0.value - extent).abs() < 1e-12);
+    }
+
+    #[test]
+    fn elliptical_hollow() {
+        // A circular section is a pipe, apart from the thin-walled approximation of its torsion
+        let x = StructuralShape::new_elliptical_hollow(0.2, 0.2, 0.005);
+        let y = StructuralShape::new_pipe(0.1, 0.005);
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-9);
+        assert!(((x.torsion_constant() - y.torsion_constant()) / y.torsion_constant()).value.abs() < 1e-3);
+
+        // An EHS 150x75x5
+        let x = StructuralShape::new_elliptical_hollow(0.075, 0.15, 0.005);
+        let (a, b, c, d) = (0.075, 0.0375, 0.07, 0.0325);
+        assert!((x.area().value - std::f64::consts::PI * (a * b - c * d)).abs() < 1e-12);
+        assert!((x.moi_y().value - std::f64::consts::PI * (a * a * a * b - c * c * c * d) / 4.0).abs() < 1e-15);
+        assert!((x.plastic_modulus_y().value - 4.0 * (a * a * b - c * c * d) / 3.0).abs() < 1e-12);
+        assert!(x.torsion_constant() < x.polar_moi());
+        assert!((x.radius_of_gyration_min() - x.radius_of_gyration_x()).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn triangle_and_trapezoid() {
+        let x = StructuralShape::new_triangle(0.3, 0.2, 0.0);
+        let (cx, cy) = x.centroid_offset();
+        assert!((cx.value - 0.2 / 3.0).abs() < 1e-12 && (cy.value - 0.1).abs() < 1e-12);
+        assert!((x.area().value - 0.03).abs() < 1e-12);
+        assert!((x.moi_x().value - 0.2 * 0.027 / 36.0).abs() < 1e-12);
+        assert!((x.product_moi().value + 0.04 * 0.09 / 72.0).abs() < 1e-12);
+
+        // Two right triangles make a rectangle
+        let other = x.clone().with_rotation(std::f64::consts::PI).with_cog(0.4 / 3.0, 0.2);
+        let pair = CompositeShape::new().add(x.clone().with_cog(0.2 / 3.0, 0.1)).add(other);
+        let y = StructuralShape::new_rectangle(0.3, 0.2).with_cog(0.1, 0.15);
+        assert!((pair.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((pair.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!(pair.centroidal_product_moi().value.abs() < 1e-12);
+        assert!((pair.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+
+        // A symmetric trapezoid
+        let x = StructuralShape::new_trapezoid(0.1, 0.3, 0.2, 0.05);
+        assert!((x.area().value - 0.025).abs() < 1e-12);
+        let height = 0.1 * (0.3 + 2.0 * 0.2) / (3.0 * 0.5);
+        assert!((x.centroid_offset().1.value - height).abs() < 1e-12);
+        assert!((x.centroid_offset().0.value - 0.15).abs() < 1e-12);
+        assert!(x.centroidal_product_moi().value.abs() < 1e-12);
+        let flipped = StructuralShape::new_trapezoid(0.1, 0.2, 0.3, -0.05);
+        assert!((x.monosymmetry_parameter() + flipped.monosymmetry_parameter()).value.abs() < 1e-12);
+        assert!(x.monosymmetry_parameter().value.abs() > 1e-3);
+
+        // Trapezoids with equal edges are parallelograms, and those without a top are triangles
+        let x = StructuralShape::new_trapezoid(0.3, 0.2, 0.2, 0.0);
+        let y = StructuralShape::new_rectangle(0.3, 0.2);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.torsion_constant() - y.torsion_constant()).value.abs() < 0.1 * y.torsion_constant().value);
+        let x = StructuralShape::new_trapezoid(0.3, 0.2, 0.0, 0.05);
+        let y = StructuralShape::new_triangle(0.3, 0.2, 0.05);
+        assert!((x.centroidal_moi_y() - y.centroidal_moi_y()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_y() - y.plastic_modulus_y()).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn circular_primitives() {
+        let pi = std::f64::consts::PI;
+        let r = 0.1_f64;
+        let x = StructuralShape::new_semicircle(r);
+        let height = 4.0 * r / (3.0 * pi);
+        assert!((x.area().value - pi * r * r / 2.0).abs() < 1e-12);
+        assert!((x.centroid_offset().1.value - height).abs() < 1e-12);
+        assert!((x.moi_x().value - (pi / 8.0 - 8.0 / (9.0 * pi)) * r.powi(4)).abs() < 1e-15);
+        assert!((x.moi_y().value - pi * r.powi(4) / 8.0).abs() < 1e-15);
+        assert!((x.plastic_modulus_y().value - 2.0 * r.powi(3) / 3.0).abs() < 1e-12);
+        assert!((x.bounding_box().1 .1.value - (r - height)).abs() < 1e-12);
+        assert!(x.monosymmetry_parameter().value.abs() > 1e-3);
+
+        // The same shape three ways
+        for y in [
+            StructuralShape::new_circular_sector(r, pi),
+            StructuralShape::new_circular_segment(r, pi),
+        ] {
+            assert!((x.area() - y.area()).value.abs() < 1e-12);
+            assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+            assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        }
+
+        // Two semicircles make a rod
+        let pair = CompositeShape::new()
+            .add(x.clone().with_cog(0.0, height))
+            .add(x.clone().with_rotation(pi).with_cog(0.0, -height));
+        let y = StructuralShape::new_rod(r);
+        assert!((pair.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((pair.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        assert!((pair.bounding_box().0 .1 - y.bounding_box().0 .1).value.abs() < 1e-12);
+
+        // A segment cut off by a chord
+        let angle = pi / 3.0;
+        let x = StructuralShape::new_circular_segment(r, angle);
+        let area = r * r * (angle - angle.sin()) / 2.0;
+        let distance = 4.0 * r * (angle / 2.0).sin().powi(3) / (3.0 * (angle - angle.sin()));
+        assert!((x.area().value - area).abs() < 1e-12);
+        assert!((x.centroid_offset().1.value - distance + r * (angle / 2.0).cos()).abs() < 1e-12);
+        assert!((x.centroid_offset().0.value - r * (angle / 2.0).sin()).abs() < 1e-12);
+
+        // A sector, and a whole circle as a sector
+        let x = StructuralShape::new_circular_sector(r, angle).with_rotation(0.3);
+        assert!((x.area().value - r * r * angle / 2.0).abs() < 1e-12);
+        let (lower, _) = x.clone().with_rotation(0.0).bounding_box();
+        let distance = 4.0 * r * (angle / 2.0).sin() / (3.0 * angle);
+        assert!((lower.1.value + distance).abs() < 1e-12);
+        let x = StructuralShape::new_circular_sector(r, 2.0 * pi);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.plastic_modulus_y() - y.plastic_modulus_y()).value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn annular_sector() {
+        let pi = std::f64::consts::PI;
+        let x = StructuralShape::new_annular_sector(0.1, 0.004, 2.0 * pi);
+        let y = StructuralShape::new_pipe(0.1, 0.004);
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        // Slitting the pipe leaves only the open-section torsion constant
+        assert!(x.torsion_constant().value < 0.01 * y.torsion_constant().value);
+
+        // A thin half-round plate has its shear center 4r/π behind the center of its arc
+        let radius = 0.098;
+        let x = StructuralShape::new_annular_sector(0.1, 0.004, pi);
+        let bottom = x.centroid_offset().1.value;
+        let (_, y0) = x.shear_center();
+        assert!(((y0.value + bottom) / (4.0 * radius / pi) - 1.0).abs() < 1e-3);
+        let j = pi * radius * 0.004_f64.powi(3) / 3.0;
+        assert!((x.torsion_constant().value / j - 1.0).abs() < 1e-3);
+        assert!(x.warping_constant().value > 0.0);
+
+        // In a composite, a curved plate is centered at its centroid
+        let z = CompositeShape::new().add(x.clone().with_cog(1.0, 2.0));
+        let (cx, cy) = z.calculate_cog();
+        assert!((cx.value - 1.0).abs() < 1e-12 && (cy.value - 2.0).abs() < 1e-12);
+        assert!((z.centroidal_moi_x() - x.centroidal_moi_x()).value.abs() < 1e-15);
+    }
+
+    #[test]
+    fn plate_girder() {
+        // Equal flanges make an I-beam
+        let x = StructuralShape::new_plate_girder(0.5, 0.025, 0.25, 0.05, 0.25, 0.05).with_cog(0.1, 0.2);
+        let y = StructuralShape::new_ibeam(0.5, 0.25, 0.025, 0.05).with_cog(0.1, 0.2);
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-12);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-12);
+        assert!((x.torsion_constant() - y.torsion_constant()).value.abs() < 1e-12);
+        assert!((x.warping_constant() - y.warping_constant()).value.abs() < 1e-15);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        assert!((x.shear_center().1.value - 0.2).abs() < 1e-12);
+        assert!(x.monosymmetry_parameter().value.abs() < 1e-12);
+
+        // A crane girder with a larger top flange, against the same outline as a polygon
+        let x = StructuralShape::new_plate_girder(1.0, 0.012, 0.4, 0.03, 0.3, 0.02);
+        let y = PolygonShape::new(
+            &[
+                (-0.15, 0.0), (0.15, 0.0), (0.15, 0.02), (0.006, 0.02), (0.006, 0.97), (0.2, 0.97),
+                (0.2, 1.0), (-0.2, 1.0), (-0.2, 0.97), (-0.006, 0.97), (-0.006, 0.02), (-0.15, 0.02),
+            ],
+            &[],
+        );
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((x.centroidal_moi_x() - y.centroidal_moi_x()).value.abs() < 1e-12);
+        assert!((x.centroidal_moi_y() - y.centroidal_moi_y()).value.abs() < 1e-12);
+        assert!((x.centroid_offset().1 - y.centroid_offset().1).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        let (top, bottom) = x.section_modulus_x();
+        assert!(top > bottom);
+
+        // The shear center sits nearer the stiffer top flange
+        let (top, bottom) = (0.03 * 0.064, 0.02 * 0.027);
+        let height = 0.01 + 0.975 * top / (top + bottom) - x.centroid_offset().1.value;
+        assert!((x.shear_center().1.value - height).abs() < 1e-12);
+        assert!(height > 0.0);
+        let cw = 0.975 * 0.975 * top * bottom / (12.0 * (top + bottom));
+        assert!((x.warping_constant().value - cw).abs() < 1e-15);
+
+        // Monosymmetry is positive when the top flange is larger, and changes sign when flipped
+        let beta = x.monosymmetry_parameter();
+        assert!(beta.value > 0.0);
+        let flipped = StructuralShape::new_plate_girder(1.0, 0.012, 0.3, 0.02, 0.4, 0.03);
+        assert!((flipped.monosymmetry_parameter() + beta).value.abs() < 1e-12);
+        // Close to the usual approximation 0.9 hs (2ρ - 1)(1 - (Iy/Ix)^2)
+        let rho = top / (top + bottom);
+        let ratio = (x.centroidal_moi_y() / x.centroidal_moi_x()).value;
+        let approximate = 0.9 * 0.975 * (2.0 * rho - 1.0) * (1.0 - ratio * ratio);
+        assert!((beta.value / approximate - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn rolled_sections() {
+        // Zero radii give the sharp-cornered shapes
+        let x = StructuralShape::new_rolled_ibeam(0.3, 0.15, 0.0071, 0.0107, 0.0);
+        let y = StructuralShape::new_ibeam(0.3, 0.15, 0.0071, 0.0107);
+        assert!((x.area() - y.area()).value.abs() < 1e-15);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.torsion_constant() - y.torsion_constant()).value.abs() < 1e-15);
+        let x = StructuralShape::new_rounded_boxbeam(0.2, 0.1, 0.008, 0.0, 0.0);
+        let y = StructuralShape::new_boxbeam(0.2, 0.1, 0.008);
+        assert!((x.area() - y.area()).value.abs() < 1e-15);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-15);
+
+        // A W12X26 in inches against the AISC table, which includes the fillets
+        let x = StructuralShape::new_rolled_ibeam(12.2, 6.49, 0.23, 0.38, 0.30);
+        let fillets = 4.0 * (1.0 - std::f64::consts::PI / 4.0) * 0.09;
+        let sharp = 2.0 * 6.49 * 0.38 + (12.2 - 0.76) * 0.23;
+        assert!((x.area().value - sharp - fillets).abs() < 1e-12);
+        assert!((x.area().value / 7.65 - 1.0).abs() < 0.01);
+        assert!((x.moi_x().value / 204.0 - 1.0).abs() < 0.01);
+        assert!((x.moi_y().value / 17.3 - 1.0).abs() < 0.01);
+        assert!((x.plastic_modulus_x().value / 37.2 - 1.0).abs() < 0.01);
+        assert!((x.torsion_constant().value / 0.300 - 1.0).abs() < 0.01);
+        assert!(x.moi_x() > StructuralShape::new_ibeam(12.2, 6.49, 0.23, 0.38).moi_x());
+
+        // A rectangular hollow section, with the EN 10219 torsion constant
+        let (h, b, t, ro, ri) = (0.2, 0.1, 0.008, 0.016, 0.008);
+        let x = StructuralShape::new_rounded_boxbeam(h, b, t, ro, ri);
+        let corners = 4.0 - std::f64::consts::PI;
+        let area = b * h - corners * ro * ro - ((b - 2.0 * t) * (h - 2.0 * t) - corners * ri * ri);
+        assert!((x.area().value - area).abs() < 1e-12);
+        let rc = (ro + ri) / 2.0;
+        let perimeter = 2.0 * ((b - t) + (h - t)) - 2.0 * rc * corners;
+        let enclosed = (b - t) * (h - t) - rc * rc * corners;
+        let j = t * t * t * perimeter / 3.0 + 4.0 * enclosed * enclosed * t / perimeter;
+        assert!((x.torsion_constant().value - j).abs() < 1e-15);
+        assert!(x.moi_x() < StructuralShape::new_boxbeam(h, b, t).moi_x());
+        assert!((x.centroid_offset().0.value - b / 2.0).abs() < 1e-12);
+        assert!(x.monosymmetry_parameter().value.abs() < 1e-12);
+    }
+
+    #[test]
+    fn box_girders() {
+        // Equal thicknesses and no overhang give the plain box beam
+        let x = StructuralShape::new_box_girder(0.3, 0.2, 0.01, 0.01, 0.0);
+        let y = StructuralShape::new_boxbeam(0.3, 0.2, 0.01);
+        assert!((x.area() - y.area()).value.abs() < 1e-15);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.torsion_constant() - y.torsion_constant()).value.abs() < 1e-15);
+        assert!((x.warping_constant() - y.warping_constant()).value.abs() < 1e-18);
+
+        // Thicker flanges than webs, against a thin-walled section along the same midline
+        let (h, b, tf, tw) = (0.3, 0.2, 0.02, 0.008);
+        let x = StructuralShape::new_box_girder(h, b, tf, tw, 0.0);
+        let (c, d) = ((b - tw) / 2.0, (h - tf) / 2.0);
+        let nodes = [(-c, -d), (c, -d), (c, d), (-c, d)];
+        let y = ThinWalledShape::new(
+            &nodes,
+            &[(0, 1, tf), (1, 2, tw), (2, 3, tf), (3, 0, tw)],
+        );
+        assert!(((x.torsion_constant() / y.torsion_constant()).value - 1.0).abs() < 1e-12);
+        assert!(((x.warping_constant() / y.warping_constant()).value - 1.0).abs() < 1e-9);
+        assert!(x.warping_constant().value > 0.0);
+        let area = b * h - (b - 2.0 * tw) * (h - 2.0 * tf);
+        assert!((x.area().value - area).abs() < 1e-15);
+
+        // A box girder with overhanging flanges, against the same outline as a polygon
+        let x = StructuralShape::new_box_girder(1.5, 1.2, 0.03, 0.015, 0.1);
+        let y = PolygonShape::new(
+            &[
+                (-0.6, 0.0), (0.6, 0.0), (0.6, 0.03), (0.5, 0.03), (0.5, 1.47), (0.6, 1.47),
+                (0.6, 1.5), (-0.6, 1.5), (-0.6, 1.47), (-0.5, 1.47), (-0.5, 0.03), (-0.6, 0.03),
+            ],
+            &[vec![(-0.485, 0.03), (0.485, 0.03), (0.485, 1.47), (-0.485, 1.47)]],
+        );
+        assert!((x.area() - y.area()).value.abs() < 1e-12);
+        assert!((x.centroidal_moi_x() - y.centroidal_moi_x()).value.abs() < 1e-12);
+        assert!((x.centroidal_moi_y() - y.centroidal_moi_y()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-12);
+        assert!((x.plastic_modulus_y() - y.plastic_modulus_y()).value.abs() < 1e-12);
+        assert!(x.shear_center().0.value.abs() < 1e-12);
+        assert!(x.monosymmetry_parameter().value.abs() < 1e-12);
+
+        // The overhangs add open outstands to the single cell
+        let (c, d) = (0.5925, 0.735);
+        let nodes = [
+            (-0.4925, -d), (0.4925, -d), (0.4925, d), (-0.4925, d),
+            (-c, -d), (c, -d), (c, d), (-c, d),
+        ];
+        let walls = [
+            (0, 1, 0.03), (1, 2, 0.015), (2, 3, 0.03), (3, 0, 0.015),
+            (0, 4, 0.03), (1, 5, 0.03), (2, 6, 0.03), (3, 7, 0.03),
+        ];
+        let y = ThinWalledShape::new(&nodes, &walls);
+        assert!(((x.torsion_constant() / y.torsion_constant()).value - 1.0).abs() < 1e-12);
+        assert!(((x.warping_constant() / y.warping_constant()).value - 1.0).abs() < 1e-9);
+        let closed = StructuralShape::new_box_girder(1.5, 1.0, 0.03, 0.015, 0.0);
+        assert!(x.warping_constant() > closed.warping_constant());
+    }
+
+    #[test]
+    fn regular_polygons() {
+        // A hexagon, and the same hexagon from the distance across its flats
+        let x = StructuralShape::new_regular_polygon(6, 0.1);
+        let y = StructuralShape::new_regular_polygon_across_flats(6, 0.1 * 3f64.sqrt());
+        let area = 3.0 * 3f64.sqrt() / 2.0 * 0.01;
+        let moi = 5.0 * 3f64.sqrt() / 16.0 * 1e-4;
+        assert!((x.area().value - area).abs() < 1e-15);
+        assert!((x.moi_x().value - moi).abs() < 1e-15);
+        assert!((x.moi_y().value - moi).abs() < 1e-15);
+        assert!((y.area() - x.area()).value.abs() < 1e-15);
+        assert!(x.monosymmetry_parameter().value.abs() < 1e-12);
+        assert_eq!(x.warping_constant().value, 0.0);
+        // Roark gives 1.035 s^4 for the torsion constant of a hexagon with sides s
+        assert!((x.torsion_constant().value / 1.035e-4 - 1.0).abs() < 0.02);
+
+        // A square stands on a flat, so it matches a rectangle
+        let x = StructuralShape::new_regular_polygon_across_flats(4, 0.2);
+        let y = StructuralShape::new_rectangle(0.2, 0.2);
+        assert!((x.area() - y.area()).value.abs() < 1e-15);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-15);
+        assert!((x.centroid_offset().0 - meters(0.1)).value.abs() < 1e-15);
+
+        // An equilateral triangle has its center of gravity a third of the way up
+        let x = StructuralShape::new_regular_polygon(3, 0.2);
+        let y = StructuralShape::new_triangle(0.3, 0.2 * 3f64.sqrt(), 0.1 * 3f64.sqrt());
+        assert!((x.area() - y.area()).value.abs() < 1e-15);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.centroid_offset().1 - meters(0.1)).value.abs() < 1e-15);
+    }
+
+    #[test]
+    fn hollow_regular_polygons() {
+        // An octagonal tube, against the same outline as a polygon with a hole
+        let x = StructuralShape::new_hollow_regular_polygon_across_flats(8, 0.3, 0.006);
+        let corner = |radius: f64, i: usize| {
+            let angle = (i as f64 + 0.5) * std::f64::consts::FRAC_PI_4
+                - std::f64::consts::FRAC_PI_2;
+            (radius * angle.cos(), radius * angle.sin())
+        };
+        let radius = 0.15 / (std::f64::consts::PI / 8.0).cos();
+        let inner = 0.144 / (std::f64::consts::PI / 8.0).cos();
+        let outer: Vec<(f64, f64)> = (0..8).map(|i| corner(radius, i)).collect();
+        let hole: Vec<(f64, f64)> = (0..8).map(|i| corner(inner, i)).collect();
+        let y = PolygonShape::new(&outer, &[hole]);
+        assert!((x.area() - y.area()).value.abs() < 1e-15);
+        assert!((x.moi_x() - y.moi_x()).value.abs() < 1e-15);
+        assert!((x.moi_y() - y.moi_y()).value.abs() < 1e-15);
+        assert!((x.plastic_modulus_x() - y.plastic_modulus_x()).value.abs() < 1e-15);
+        assert_eq!(x.warping_constant().value, 0.0);
+        assert_eq!(x.shear_center(), (meters(0.0), meters(0.0)));
+
+        // Closed thin-walled torsion along the midline
+        let perimeter = 16.0 * 0.147 * (std::f64::consts::PI / 8.0).tan();
+        let enclosed = perimeter * 0.147 / 2.0;
+        let j = 0.006f64.powi(3) * perimeter / 3.0 + 4.0 * enclosed * enclosed * 0.006 / perimeter;
+        assert!((x.torsion_constant().value - j).abs() < 1e-15);
+
+        // With many sides, it approaches a pipe
+        let x = StructuralShape::new_hollow_regular_polygon(64, 0.15, 0.006);
+        let y = StructuralShape::new_pipe(0.15, 0.006);
+        assert!((x.area() / y.area()).value > 0.99);
+        assert!((x.moi_x() / y.moi_x()).value > 0.99);
+        assert!((x.torsion_constant() / y.torsion_constant()).value > 0.99);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least three sides")]
+    fn regular_polygon_rejects_two_sides() {
+        StructuralShape::new_regular_polygon(2, 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least three sides")]
+    fn regular_polygon_rejects_no_sides() {
+        StructuralShape::new_regular_polygon_across_flats(0, 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least three sides")]
+    fn hollow_regular_polygon_rejects_one_side() {
+        StructuralShape::new_hollow_regular_polygon(1, 0.1, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "thinner")]
+    fn hollow_regular_polygon_rejects_thick_walls() {
+        // The flats of a hexagon are 0.866 from its center
+        StructuralShape::new_hollow_regular_polygon(6, 1.0, 0.9);
+    }
+
+    #[test]
+    fn aisc_catalog() {
+        // Against the AISC Manual, in inches
+        let inch = 0.0254f64;
+        let close = |value: f64, table: f64, power: i32| {
+            (value / inch.powi(power) / table - 1.0).abs() < 0.01
+        };
+        let x = StructuralShape::from_designation("W12X26").unwrap();
+        assert!(close(x.area().value, 7.65, 2));
+        assert!(close(x.moi_x().value, 204.0, 4));
+        assert!(close(x.moi_y().value, 17.3, 4));
+        assert!(close(x.plastic_modulus_x().value, 37.2, 3));
+        assert!(close(x.torsion_constant().value, 0.300, 4));
+        assert!(close(x.warping_constant().value, 607.0, 6));
+        let x = StructuralShape::from_designation("HSS8X4X1/2").unwrap();
+        assert!(close(x.area().value, 9.74, 2));
+        assert!(close(x.moi_x().value, 71.8, 4));
+        let x = StructuralShape::from_designation("Pipe4STD").unwrap();
+        assert!(close(x.area().value, 2.96, 2));
+        assert!(close(x.moi_x().value, 6.82, 4));
+        let x = StructuralShape::from_designation("L4X4X1/2").unwrap();
+        assert!(close(x.area().value, 3.75, 2));
+        let x = StructuralShape::from_designation("C12X20.7").unwrap();
+        assert!(close(x.area().value, 6.08, 2));
+        assert!(close(x.moi_x().value, 129.0, 4));
+        assert!((x.moi_y().value / inch.powi(4) / 3.86 - 1.0).abs() < 0.02);
+        assert!((x.centroid_offset().0.value / inch / 0.698 - 1.0).abs() < 0.01);
+        let x = StructuralShape::from_designation("S10X35").unwrap();
+        assert!(close(x.moi_x().value, 147.0, 4));
+        assert!((x.moi_y().value / inch.powi(4) / 8.36 - 1.0).abs() < 0.02);
+
+        // Designations ignore case and spaces, and tees are cut from W shapes
+        let x = StructuralShape::from_designation("w12 x 26").unwrap();
+        assert_eq!(x.area(), StructuralShape::from_designation("W12X26").unwrap().area());
+        let tee = catalog::aisc::lookup("WT6X13").unwrap();
+        assert_eq!(tee.area(), x.cut_tee().unwrap().area());
+        assert!(StructuralShape::from_designation("W12X27").is_none());
+        assert!(StructuralShape::from_designation("WT6").is_none());
+        for designation in catalog::aisc::designations() {
+            assert!(catalog::aisc::lookup(&designation).is_some(), "{}", designation);
+        }
+    }
+
+    #[test]
+    fn european_catalog() {
+        // Against the published section tables, in centimeters
+        let close = |value: f64, table: f64, power: i32, tolerance: f64| {
+            (value / 0.01f64.powi(power) / table - 1.0).abs() < tolerance
+        };
+        let x = StructuralShape::from_designation("IPE 300").unwrap();
+        assert!(close(x.area().value, 53.8, 2, 0.01));
+        assert!(close(x.moi_x().value, 8356.0, 4, 0.01));
+        assert!(close(x.moi_y().value, 603.8, 4, 0.01));
+        assert!(close(x.plastic_modulus_x().value, 628.4, 3, 0.01));
+        assert!(close(x.torsion_constant().value, 20.12, 4, 0.02));
+        let x = StructuralShape::from_designation("HEB 200").unwrap();
+        assert!(close(x.area().value, 78.1, 2, 0.01));
+        assert!(close(x.moi_x().value, 5696.0, 4, 0.01));
+        assert!(close(x.torsion_constant().value, 59.28, 4, 0.02));
+        let x = StructuralShape::from_designation("UPN 200").unwrap();
+        assert!(close(x.area().value, 32.2, 2, 0.01));
+        assert!(close(x.moi_x().value, 1910.0, 4, 0.01));
+        assert!(close(x.moi_y().value, 148.0, 4, 0.06));
+        let x = StructuralShape::from_designation("RHS 200x100x8").unwrap();
+        assert!(close(x.area().value, 44.8, 2, 0.01));
+        assert!(close(x.moi_x().value, 2234.0, 4, 0.01));
+        assert!(close(x.moi_y().value, 739.0, 4, 0.01));
+        let x = StructuralShape::from_designation("CHS 168.3x8").unwrap();
+        assert!(close(x.area().value, 40.3, 2, 0.01));
+        assert!(close(x.moi_x().value, 1297.0, 4, 0.01));
+
+        // Other ways of writing the same designations
+        let area = |designation: &str| catalog::european::lookup(designation).unwrap().area();
+        assert_eq!(area("HEB 200"), area("HE 200 B"));
+        assert_eq!(area("heb200"), area("HEB 200"));
+        assert_eq!(area("SHS 100x5"), area("SHS 100x100x5"));
+        assert!(catalog::european::lookup("IPE 310").is_none());
+        assert!(catalog::european::lookup("RHS 200x100").is_none());
+        assert!(catalog::european::lookup("CHS 20x10").is_none());
+        assert!(catalog::european::lookup("SHS 100x100x-5").is_none());
+        for designation in catalog::european::designations() {
+            assert!(catalog::european::lookup(designation).is_some(), "{}", designation);
+        }
+    }
+
+    #[test]
+    fn pipe_schedules() {
+        use catalog::pipe::{lookup, Schedule};
+
+        // NPS 4 schedule 40 against the published area and moment of inertia, in inches
+        let inch = 0.0254f64;
+        let x = lookup("4", Schedule::S40).unwrap();
+        assert!((x.area().value / inch.powi(2) / 3.17 - 1.0).abs() < 0.01);
+        assert!((x.moi_x().value / inch.powi(4) / 7.23 - 1.0).abs() < 0.01);
+        let y = StructuralShape::new_pipe(2.25 * inch, 0.237 * inch);
+        assert_eq!(x.area(), y.area());
+
+        // Sizes can be written in several ways
+        let area = |size: &str, schedule: Schedule| lookup(size, schedule).unwrap().area();
+        assert_eq!(area("DN100", Schedule::S40), area("4", Schedule::S40));
+        assert_eq!(area("dn 40", Schedule::S80), area("1-1/2", Schedule::S80));
+        assert_eq!(area("1 1/2", Schedule::S80), area("1.5", Schedule::S80));
+
+        // Standard weight matches schedule 40 up to NPS 10, but not above
+        assert_eq!(area("10", Schedule::Standard), area("10", Schedule::S40));
+        assert!(area("12", Schedule::Standard) < area("12", Schedule::S40));
+        assert!(area("2", Schedule::DoubleExtraStrong) > area("2", Schedule::S160));
+        assert!(lookup("3-1/2", Schedule::S160).is_none());
+        assert!(lookup("7", Schedule::S40).is_none());
+        assert!(lookup("DN", Schedule::S40).is_none());
+        for size in catalog::pipe::sizes() {
+            assert!(lookup(size, Schedule::S40).is_some(), "{}", size);
+        }
+    }
+
+    #[test]
+    fn timber_sizes() {
+        use catalog::timber::{glulam, lumber, lvl};
+        let inch = 0.0254;
+        let rectangle = |depth: f64, width: f64| {
+            StructuralShape::new_rectangle(depth * inch, width * inch)
+        };
+
+        // Nominal sizes are dressed down, with the larger dimension as the depth
+        assert_eq!(lumber("2x4").unwrap().area(), rectangle(3.5, 1.5).area());
+        assert_eq!(lumber("2x10").unwrap().moi_x(), rectangle(9.25, 1.5).moi_x());
+        assert_eq!(lumber("10 X 2").unwrap().moi_x(), rectangle(9.25, 1.5).moi_x());
+        assert_eq!(lumber("1x6").unwrap().area(), rectangle(5.5, 0.75).area());
+        assert_eq!(lumber("6x6").unwrap().area(), rectangle(5.5, 5.5).area());
+        assert_eq!(lumber("6x10").unwrap().moi_x(), rectangle(9.5, 5.5).moi_x());
+        assert!(lumber("2x4").unwrap().area() < rectangle(4.0, 2.0).area());
+        assert!(lumber("2x7").is_none());
+        assert!(lumber("2x4x8").is_none());
+        assert!(lumber("two by four").is_none());
+
+        // Glulam must be a standard width and a whole number of laminations
+        assert_eq!(glulam("5-1/8x12").unwrap().area(), rectangle(12.0, 5.125).area());
+        assert_eq!(glulam("6 3/4 x 11").unwrap().area(), rectangle(11.0, 6.75).area());
+        assert!(glulam("6.75x12").is_some());
+        assert!(glulam("5x12").is_none());
+        assert!(glulam("4x12").is_none());
+        assert!(glulam("3-1/8x3").is_none());
+
+        // LVL comes in plies of 1-3/4 in and a set of depths
+        assert_eq!(lvl("1-3/4x11-7/8").unwrap().area(), rectangle(11.875, 1.75).area());
+        assert!(lvl("3.5x14").is_some());
+        assert!(lvl("1-3/4x12").is_none());
+        assert!(lvl("2x10").is_none());
+    }
 }